@@ -0,0 +1,105 @@
+use time::Date;
+
+use crate::BusinessCalendar;
+
+/// The rule used to move a date that is not a business day, as defined by ISDA.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BusinessDayConvention {
+    /// Moves to the next business day.
+    Following,
+    /// Moves to the next business day, unless it is in the next month, in
+    /// which case moves to the previous business day.
+    #[default]
+    ModifiedFollowing,
+    /// Moves to the previous business day.
+    Preceding,
+    /// Moves to the previous business day, unless it is in the previous month,
+    /// in which case moves to the next business day.
+    ModifiedPreceding,
+    /// Moves to the nearest business day, or the next one if both are equally near.
+    Nearest,
+    /// Keeps the date as it is.
+    Unadjusted,
+}
+
+impl BusinessDayConvention {
+    /// Adjusts the date to a business day of the calendar.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{BusinessCalendar, BusinessDayConvention::*, WeekendMask};
+    /// let calendar = BusinessCalendar::new(WeekendMask::SATURDAY_SUNDAY);
+    /// let d0 = Date::from_calendar_date(2024, August, 31).unwrap();
+    /// let d1 = Date::from_calendar_date(2024, August, 30).unwrap();
+    /// let d2 = Date::from_calendar_date(2024, September, 2).unwrap();
+    /// assert_eq!(Following.adjust(d0, &calendar), Some(d2));
+    /// assert_eq!(ModifiedFollowing.adjust(d0, &calendar), Some(d1));
+    /// assert_eq!(Unadjusted.adjust(d0, &calendar), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn adjust(self, date: Date, calendar: &BusinessCalendar) -> Option<Date> {
+        if calendar.is_business_day(date) {
+            return Some(date);
+        }
+
+        match self {
+            Self::Following => calendar.next_business_day(date),
+            Self::ModifiedFollowing => match calendar.next_business_day(date) {
+                Some(x) if x.month() == date.month() => Some(x),
+                _ => calendar.previous_business_day(date),
+            },
+            Self::Preceding => calendar.previous_business_day(date),
+            Self::ModifiedPreceding => match calendar.previous_business_day(date) {
+                Some(x) if x.month() == date.month() => Some(x),
+                _ => calendar.next_business_day(date),
+            },
+            Self::Nearest => {
+                let next = calendar.next_business_day(date);
+                let previous = calendar.previous_business_day(date);
+                match (next, previous) {
+                    (Some(x), Some(y)) if x - date <= date - y => Some(x),
+                    (_, Some(y)) => Some(y),
+                    (x, None) => x,
+                }
+            }
+            Self::Unadjusted => Some(date),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Date;
+    use time::Month::*;
+
+    use crate::{BusinessCalendar, BusinessDayConvention::*, WeekendMask};
+
+    #[test]
+    fn adjust() {
+        let d0 = Date::from_calendar_date(2024, March, 29).unwrap();
+        let calendar = BusinessCalendar::new(WeekendMask::SATURDAY_SUNDAY).with_holiday(d0);
+        let date = |m, d| Date::from_calendar_date(2024, m, d).unwrap();
+
+        // Friday, March 29 is a holiday.
+        assert_eq!(Following.adjust(d0, &calendar), Some(date(April, 1)));
+        assert_eq!(
+            ModifiedFollowing.adjust(d0, &calendar),
+            Some(date(March, 28))
+        );
+        assert_eq!(Preceding.adjust(d0, &calendar), Some(date(March, 28)));
+        assert_eq!(
+            ModifiedPreceding.adjust(d0, &calendar),
+            Some(date(March, 28))
+        );
+        assert_eq!(Nearest.adjust(d0, &calendar), Some(date(March, 28)));
+
+        // Sunday, June 2, and Saturday, June 1.
+        let d1 = date(June, 1);
+        assert_eq!(ModifiedPreceding.adjust(d1, &calendar), Some(date(June, 3)));
+        assert_eq!(Nearest.adjust(d1, &calendar), Some(date(May, 31)));
+        assert_eq!(
+            Nearest.adjust(date(June, 2), &calendar),
+            Some(date(June, 3))
+        );
+    }
+}
@@ -0,0 +1,389 @@
+use time::Date;
+
+use crate::util::days_in_year_month;
+use crate::CalendarDuration;
+
+mod convention;
+pub use convention::*;
+
+mod weekend;
+pub use weekend::*;
+
+/// A calendar of business days, defined by a weekend and a set of holidays.
+///
+/// Half days are business days that are marked as such, e.g. the day after
+/// Thanksgiving on US exchanges, and are only informational.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::{BusinessCalendar, WeekendMask};
+/// let d0 = Date::from_calendar_date(2024, December, 24).unwrap();
+/// let d1 = Date::from_calendar_date(2024, December, 25).unwrap();
+/// let d2 = Date::from_calendar_date(2024, December, 27).unwrap();
+/// let calendar = BusinessCalendar::new(WeekendMask::SATURDAY_SUNDAY)
+///     .with_holiday(d1)
+///     .with_holiday(d1.next_day().unwrap())
+///     .with_half_day(d0);
+///
+/// assert!(calendar.is_half_day(d0));
+/// assert!(!calendar.is_business_day(d1));
+/// assert_eq!(calendar.next_business_day(d0), Some(d2));
+/// ```
+///
+/// With the `serde` feature, a calendar is declared with the names of the
+/// weekend days and `YYYY-MM-DD` dates, all of them optional:
+///
+/// ```toml
+/// weekend = ["friday", "saturday"]
+/// holidays = [2024-12-25, 2024-12-26]
+/// half_days = [2024-12-24]
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct BusinessCalendar {
+    weekend: WeekendMask,
+    holidays: Vec<Date>,
+    half_days: Vec<Date>,
+}
+
+/// Inserts the date into the sorted list, unless it is already present.
+fn insert(dates: &mut Vec<Date>, date: Date) {
+    if let Err(index) = dates.binary_search(&date) {
+        dates.insert(index, date);
+    }
+}
+
+impl BusinessCalendar {
+    /// Creates a new `BusinessCalendar` with the weekend and no holidays.
+    #[must_use]
+    pub const fn new(weekend: WeekendMask) -> Self {
+        Self {
+            weekend,
+            holidays: Vec::new(),
+            half_days: Vec::new(),
+        }
+    }
+
+    /// Adds a holiday.
+    #[must_use]
+    pub fn with_holiday(mut self, date: Date) -> Self {
+        insert(&mut self.holidays, date);
+        self
+    }
+
+    /// Adds several holidays.
+    #[must_use]
+    pub fn with_holidays(mut self, dates: impl IntoIterator<Item = Date>) -> Self {
+        dates
+            .into_iter()
+            .for_each(|x| insert(&mut self.holidays, x));
+        self
+    }
+
+    /// Marks a business day as a half day.
+    #[must_use]
+    pub fn with_half_day(mut self, date: Date) -> Self {
+        insert(&mut self.half_days, date);
+        self
+    }
+
+    /// Marks several business days as half days.
+    #[must_use]
+    pub fn with_half_days(mut self, dates: impl IntoIterator<Item = Date>) -> Self {
+        dates
+            .into_iter()
+            .for_each(|x| insert(&mut self.half_days, x));
+        self
+    }
+
+    /// Returns the weekend of the calendar.
+    #[must_use]
+    pub const fn weekend(&self) -> WeekendMask {
+        self.weekend
+    }
+
+    /// Returns the holidays of the calendar in ascending order.
+    #[must_use]
+    pub fn holidays(&self) -> &[Date] {
+        &self.holidays
+    }
+
+    /// Returns the half days of the calendar in ascending order.
+    #[must_use]
+    pub fn half_days(&self) -> &[Date] {
+        &self.half_days
+    }
+}
+
+impl BusinessCalendar {
+    /// Returns `true` if the date is a holiday.
+    #[must_use]
+    pub fn is_holiday(&self, date: Date) -> bool {
+        self.holidays.binary_search(&date).is_ok()
+    }
+
+    /// Returns `true` if the date is a business day marked as a half day.
+    #[must_use]
+    pub fn is_half_day(&self, date: Date) -> bool {
+        self.is_business_day(date) && self.half_days.binary_search(&date).is_ok()
+    }
+
+    /// Returns `true` if the date is neither part of the weekend nor a holiday.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{BusinessCalendar, WeekendMask};
+    /// let calendar = BusinessCalendar::new(WeekendMask::FRIDAY_SATURDAY);
+    /// let d0 = Date::from_calendar_date(2024, January, 5).unwrap();
+    /// assert!(!calendar.is_business_day(d0));
+    /// assert!(calendar.is_business_day(d0.next_day().unwrap().next_day().unwrap()));
+    /// ```
+    #[must_use]
+    pub fn is_business_day(&self, date: Date) -> bool {
+        !self.weekend.contains(date.weekday()) && !self.is_holiday(date)
+    }
+
+    /// Returns the first business day strictly after the date.
+    #[must_use]
+    pub fn next_business_day(&self, date: Date) -> Option<Date> {
+        if self.weekend.weekend_days() == 7 {
+            return None;
+        }
+
+        let mut date = date.next_day()?;
+        while !self.is_business_day(date) {
+            date = date.next_day()?;
+        }
+
+        Some(date)
+    }
+
+    /// Returns the last business day strictly before the date.
+    #[must_use]
+    pub fn previous_business_day(&self, date: Date) -> Option<Date> {
+        if self.weekend.weekend_days() == 7 {
+            return None;
+        }
+
+        let mut date = date.previous_day()?;
+        while !self.is_business_day(date) {
+            date = date.previous_day()?;
+        }
+
+        Some(date)
+    }
+
+    /// Returns the date if it is a business day, or the next business day.
+    #[must_use]
+    pub fn roll_forward(&self, date: Date) -> Option<Date> {
+        match self.is_business_day(date) {
+            true => Some(date),
+            false => self.next_business_day(date),
+        }
+    }
+
+    /// Returns the date if it is a business day, or the previous business day.
+    #[must_use]
+    pub fn roll_backward(&self, date: Date) -> Option<Date> {
+        match self.is_business_day(date) {
+            true => Some(date),
+            false => self.previous_business_day(date),
+        }
+    }
+
+    /// Shifts the date by the provided number of business days.
+    ///
+    /// The date itself does not have to be a business day, and is returned
+    /// unchanged if the number of days is zero.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{BusinessCalendar, WeekendMask};
+    /// let calendar = BusinessCalendar::new(WeekendMask::SATURDAY_SUNDAY);
+    /// let d0 = Date::from_calendar_date(2024, January, 5).unwrap();
+    /// let d1 = Date::from_calendar_date(2024, January, 9).unwrap();
+    /// assert_eq!(calendar.checked_add_business_days(d0, 2), Some(d1));
+    /// assert_eq!(calendar.checked_add_business_days(d1, -2), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn checked_add_business_days(&self, date: Date, days: i64) -> Option<Date> {
+        let mut date = date;
+        for _ in 0..days.unsigned_abs() {
+            date = match days.is_positive() {
+                true => self.next_business_day(date)?,
+                false => self.previous_business_day(date)?,
+            };
+        }
+
+        Some(date)
+    }
+
+    /// Returns the number of business days from the start, inclusive, to the
+    /// end, exclusive, or its negation if the end precedes the start.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{BusinessCalendar, WeekendMask};
+    /// let calendar = BusinessCalendar::new(WeekendMask::SATURDAY_SUNDAY);
+    /// let d0 = Date::from_calendar_date(2024, January, 1).unwrap();
+    /// let d1 = Date::from_calendar_date(2024, February, 1).unwrap();
+    /// assert_eq!(calendar.business_days_between(d0, d1), 23);
+    /// assert_eq!(calendar.business_days_between(d1, d0), -23);
+    /// ```
+    #[must_use]
+    pub fn business_days_between(&self, start: Date, end: Date) -> i64 {
+        match start <= end {
+            true => self.count_business_days(start, end),
+            false => -self.count_business_days(end, start),
+        }
+    }
+
+    fn count_business_days(&self, start: Date, end: Date) -> i64 {
+        let days = (end - start).whole_days();
+        let week = 7 - self.weekend.weekend_days() as i64;
+        let mut count = days / 7 * week;
+
+        let mut weekday = start.weekday();
+        for _ in 0..days % 7 {
+            count += !self.weekend.contains(weekday) as i64;
+            weekday = weekday.next();
+        }
+
+        let from = self.holidays.partition_point(|x| *x < start);
+        let to = self.holidays.partition_point(|x| *x < end);
+        let holidays = self.holidays[from..to].iter();
+        count
+            - holidays
+                .filter(|x| !self.weekend.contains(x.weekday()))
+                .count() as i64
+    }
+}
+
+impl BusinessCalendar {
+    /// Returns `true` if the date is the last business day of its month.
+    fn is_last_business_day(&self, date: Date) -> bool {
+        let length = days_in_year_month(date.year(), date.month());
+        let last = date.replace_day(length).unwrap();
+        self.roll_backward(last) == Some(date)
+    }
+
+    /// Adds the duration to the date and adjusts the result with the provided
+    /// [`BusinessDayConvention`].
+    ///
+    /// With the end-of-month rule, a date that is the last business day of
+    /// its month is moved to the last business day of the resulting month.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{BusinessCalendar, BusinessDayConvention, WeekendMask};
+    /// # use timext::ext::NumericCalendarDuration;
+    /// let calendar = BusinessCalendar::new(WeekendMask::SATURDAY_SUNDAY);
+    /// let convention = BusinessDayConvention::ModifiedFollowing;
+    /// let d0 = Date::from_calendar_date(2024, April, 30).unwrap();
+    /// let d1 = Date::from_calendar_date(2024, May, 30).unwrap();
+    /// let d2 = Date::from_calendar_date(2024, May, 31).unwrap();
+    /// assert_eq!(calendar.checked_add_adjusted(d0, 1.months(), convention, false), Some(d1));
+    /// assert_eq!(calendar.checked_add_adjusted(d0, 1.months(), convention, true), Some(d2));
+    /// ```
+    #[must_use]
+    pub fn checked_add_adjusted(
+        &self,
+        date: Date,
+        duration: CalendarDuration,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+    ) -> Option<Date> {
+        let result = CalendarDuration::checked_date_add(date, duration)?;
+        match end_of_month && self.is_last_business_day(date) {
+            true => {
+                let length = days_in_year_month(result.year(), result.month());
+                self.roll_backward(result.replace_day(length).ok()?)
+            }
+            false => convention.adjust(result, self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::{Date, Weekday};
+
+    use crate::ext::NumericCalendarDuration;
+    use crate::{BusinessCalendar, BusinessDayConvention, WeekendMask};
+
+    fn calendar() -> BusinessCalendar {
+        let holidays = [(January, 1), (January, 15), (February, 19), (May, 27)];
+        let holidays = holidays.map(|(m, d)| Date::from_calendar_date(2024, m, d).unwrap());
+        BusinessCalendar::new(WeekendMask::SATURDAY_SUNDAY).with_holidays(holidays)
+    }
+
+    #[test]
+    fn shift() {
+        let calendar = calendar();
+        let d0 = Date::from_calendar_date(2024, January, 12).unwrap();
+        let d1 = Date::from_calendar_date(2024, January, 16).unwrap();
+        let d2 = Date::from_calendar_date(2024, January, 13).unwrap();
+        assert_eq!(calendar.next_business_day(d0), Some(d1));
+        assert_eq!(calendar.previous_business_day(d1), Some(d0));
+        assert_eq!(calendar.roll_forward(d2), Some(d1));
+        assert_eq!(calendar.roll_backward(d2), Some(d0));
+        assert_eq!(calendar.checked_add_business_days(d2, 1), Some(d1));
+        assert_eq!(calendar.checked_add_business_days(d2, 0), Some(d2));
+
+        let none = BusinessCalendar::new(WeekendMask::new(&[
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+            Weekday::Sunday,
+        ]));
+        assert_eq!(none.next_business_day(d0), None);
+    }
+
+    #[test]
+    fn count_matches_shift() {
+        let calendar = calendar();
+        let start = Date::from_calendar_date(2023, December, 20).unwrap();
+        for offset in 0..200 {
+            let end = start + time::Duration::days(offset);
+            let expected = (0..offset)
+                .map(|x| start + time::Duration::days(x))
+                .filter(|x| calendar.is_business_day(*x))
+                .count() as i64;
+
+            assert_eq!(calendar.business_days_between(start, end), expected);
+            assert_eq!(calendar.business_days_between(end, start), -expected);
+        }
+    }
+
+    #[test]
+    fn end_of_month() {
+        let d0 = Date::from_calendar_date(2024, March, 29).unwrap();
+        let calendar = calendar().with_holiday(d0);
+        let convention = BusinessDayConvention::Following;
+        let d1 = Date::from_calendar_date(2024, February, 29).unwrap();
+        let d2 = Date::from_calendar_date(2024, March, 28).unwrap();
+        let d3 = Date::from_calendar_date(2024, April, 1).unwrap();
+        let add = |x, eom| calendar.checked_add_adjusted(x, 1.months(), convention, eom);
+        assert_eq!(add(d1, true), Some(d2));
+        assert_eq!(add(d1, false), Some(d3));
+        assert_eq!(
+            add(d2, true),
+            Some(Date::from_calendar_date(2024, April, 30).unwrap())
+        );
+    }
+
+    #[test]
+    fn weekends() {
+        let d0 = Date::from_calendar_date(2024, January, 5).unwrap();
+        let sunday = BusinessCalendar::new(WeekendMask::SUNDAY);
+        let d1 = Date::from_calendar_date(2024, January, 8).unwrap();
+        assert_eq!(sunday.checked_add_business_days(d0, 2), Some(d1));
+
+        let none = BusinessCalendar::new(WeekendMask::NONE);
+        let d2 = Date::from_calendar_date(2024, January, 12).unwrap();
+        assert_eq!(none.business_days_between(d0, d2), 7);
+    }
+}
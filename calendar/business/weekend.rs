@@ -0,0 +1,56 @@
+use time::Weekday;
+
+/// The set of days of the week that are not business days.
+///
+/// ```rust
+/// # use time::Weekday::*;
+/// # use timext::WeekendMask;
+/// let weekend = WeekendMask::new(&[Friday, Saturday]);
+/// assert_eq!(weekend, WeekendMask::FRIDAY_SATURDAY);
+/// assert!(weekend.contains(Friday));
+/// assert!(!weekend.contains(Sunday));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekendMask(u8);
+
+impl WeekendMask {
+    /// A weekend on Saturday and Sunday.
+    pub const SATURDAY_SUNDAY: Self = Self::new(&[Weekday::Saturday, Weekday::Sunday]);
+    /// A weekend on Friday and Saturday.
+    pub const FRIDAY_SATURDAY: Self = Self::new(&[Weekday::Friday, Weekday::Saturday]);
+    /// A weekend on Sunday only.
+    pub const SUNDAY: Self = Self::new(&[Weekday::Sunday]);
+    /// No weekend, every day of the week is a business day.
+    pub const NONE: Self = Self(0);
+
+    /// Creates a new `WeekendMask` from the days of the week.
+    #[must_use]
+    pub const fn new(weekdays: &[Weekday]) -> Self {
+        let mut mask = 0;
+        let mut i = 0;
+        while i < weekdays.len() {
+            mask |= 1 << weekdays[i].number_days_from_monday();
+            i += 1;
+        }
+
+        Self(mask)
+    }
+
+    /// Returns `true` if the day of the week is part of the weekend.
+    #[must_use]
+    pub const fn contains(self, weekday: Weekday) -> bool {
+        self.0 & (1 << weekday.number_days_from_monday()) != 0
+    }
+
+    /// Returns the number of days of the week that are part of the weekend.
+    #[must_use]
+    pub const fn weekend_days(self) -> u8 {
+        self.0.count_ones() as u8
+    }
+}
+
+impl Default for WeekendMask {
+    fn default() -> Self {
+        Self::SATURDAY_SUNDAY
+    }
+}
@@ -0,0 +1,384 @@
+use time::util::days_in_year;
+use time::{Date, Month};
+
+use crate::util::days_in_year_month;
+use crate::{BusinessCalendar, CalendarDuration};
+
+/// The convention used to count the days and measure the fraction of a year
+/// between two dates, e.g. to accrue interest.
+///
+/// Both the number of days and the year fraction are negative if the end date
+/// is before the start date.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::DayCount;
+/// let d0 = Date::from_calendar_date(2023, November, 1).unwrap();
+/// let d1 = Date::from_calendar_date(2024, May, 1).unwrap();
+/// assert_eq!(DayCount::Act360.day_count(d0, d1), 182);
+/// assert_eq!(DayCount::Thirty360European.day_count(d0, d1), 180);
+/// assert_eq!(DayCount::Thirty360European.year_fraction(d0, d1), 0.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayCount<'a> {
+    /// ACT/360: the actual number of days over 360.
+    Act360,
+    /// ACT/365F: the actual number of days over 365.
+    Act365Fixed,
+    /// 30/360 US: months of 30 days, with the last day of February treated
+    /// as the 30th at the start, and at the end if both dates are.
+    Thirty360Us,
+    /// 30E/360, also known as Eurobond basis: months of 30 days, with the
+    /// 31st treated as the 30th.
+    Thirty360European,
+    /// 30E/360 ISDA: months of 30 days, with the last day of a month treated
+    /// as the 30th, unless the end date is the last day of February and the
+    /// termination date of the instrument.
+    Thirty360Isda { termination: Option<Date> },
+    /// ACT/ACT ISDA: the actual number of days in each calendar year over the
+    /// length of that year.
+    ActActIsda,
+    /// ACT/ACT ICMA: the actual number of days over the length of the
+    /// reference period, times the number of periods in a year.
+    ///
+    /// Dates outside of the reference period, e.g. in a long stub, are
+    /// measured against the neighbouring periods of `12 / frequency` months,
+    /// following the end-of-month rule.
+    ActActIcma {
+        period_start: Date,
+        period_end: Date,
+        frequency: u8,
+    },
+    /// Business/252: the number of business days of the calendar over 252.
+    Business252 { calendar: &'a BusinessCalendar },
+}
+
+/// Returns the number of actual days between the dates.
+fn actual_days(start: Date, end: Date) -> i64 {
+    (end - start).whole_days()
+}
+
+/// Returns `true` if the date is the last day of February.
+fn is_last_of_february(date: Date) -> bool {
+    date.month() == Month::February
+        && date.day() == days_in_year_month(date.year(), Month::February)
+}
+
+/// Returns the number of days between the dates with months of 30 days,
+/// after the days of the month are adjusted.
+fn thirty_days(start: Date, end: Date, d1: u8, d2: u8) -> i64 {
+    let years = end.year() as i64 - start.year() as i64;
+    let months = end.month() as i64 - start.month() as i64;
+    360 * years + 30 * months + d2 as i64 - d1 as i64
+}
+
+/// Returns the number of days of the overlap of both periods.
+fn overlap(start: Date, end: Date, period_start: Date, period_end: Date) -> i64 {
+    actual_days(start.max(period_start), end.min(period_end)).max(0)
+}
+
+impl DayCount<'_> {
+    /// Returns the number of days between the dates under the convention.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::DayCount;
+    /// let d0 = Date::from_calendar_date(2007, January, 31).unwrap();
+    /// let d1 = Date::from_calendar_date(2007, February, 28).unwrap();
+    /// assert_eq!(DayCount::Act365Fixed.day_count(d0, d1), 28);
+    /// assert_eq!(DayCount::Thirty360Us.day_count(d0, d1), 28);
+    /// assert_eq!(DayCount::Thirty360Isda { termination: None }.day_count(d0, d1), 30);
+    /// ```
+    #[must_use]
+    pub fn day_count(&self, start: Date, end: Date) -> i64 {
+        if start > end {
+            return -self.day_count(end, start);
+        }
+
+        let (d1, d2) = (start.day(), end.day());
+        match self {
+            Self::Thirty360Us => {
+                let february = is_last_of_february(start);
+                let d2 = match d2 {
+                    _ if february && is_last_of_february(end) => 30,
+                    31 if d1 >= 30 || february => 30,
+                    _ => d2,
+                };
+                let d1 = match d1 {
+                    _ if february => 30,
+                    31 => 30,
+                    _ => d1,
+                };
+
+                thirty_days(start, end, d1, d2)
+            }
+            Self::Thirty360European => thirty_days(start, end, d1.min(30), d2.min(30)),
+            Self::Thirty360Isda { termination } => {
+                let d1 = match is_last_of_february(start) {
+                    true => 30,
+                    false => d1.min(30),
+                };
+                let d2 = match is_last_of_february(end) && *termination != Some(end) {
+                    true => 30,
+                    false => d2.min(30),
+                };
+
+                thirty_days(start, end, d1, d2)
+            }
+            Self::Business252 { calendar } => calendar.business_days_between(start, end),
+            _ => actual_days(start, end),
+        }
+    }
+
+    /// Returns the fraction of a year between the dates under the convention.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::DayCount;
+    /// let d0 = Date::from_calendar_date(2003, November, 1).unwrap();
+    /// let d1 = Date::from_calendar_date(2004, May, 1).unwrap();
+    /// let isda = DayCount::ActActIsda.year_fraction(d0, d1);
+    /// assert_eq!(isda, 61.0 / 365.0 + 121.0 / 366.0);
+    ///
+    /// let icma = DayCount::ActActIcma { period_start: d0, period_end: d1, frequency: 2 };
+    /// assert_eq!(icma.year_fraction(d0, d1), 0.5);
+    /// ```
+    #[must_use]
+    pub fn year_fraction(&self, start: Date, end: Date) -> f64 {
+        if start > end {
+            return -self.year_fraction(end, start);
+        }
+
+        match *self {
+            Self::Act360 => actual_days(start, end) as f64 / 360.0,
+            Self::Act365Fixed => actual_days(start, end) as f64 / 365.0,
+            Self::Thirty360Us | Self::Thirty360European | Self::Thirty360Isda { .. } => {
+                self.day_count(start, end) as f64 / 360.0
+            }
+            Self::ActActIsda => (start.year()..=end.year())
+                .map(|year| {
+                    let first = Date::from_calendar_date(year, Month::January, 1).unwrap();
+                    let days = days_in_year(year) as i64;
+                    let from = actual_days(first, start).max(0);
+                    let to = actual_days(first, end).min(days);
+                    (to - from) as f64 / days as f64
+                })
+                .sum(),
+            Self::ActActIcma {
+                period_start,
+                period_end,
+                frequency,
+            } => Self::icma(start, end, period_start, period_end, frequency),
+            Self::Business252 { calendar } => {
+                calendar.business_days_between(start, end) as f64 / 252.0
+            }
+        }
+    }
+
+    /// Returns the ACT/ACT ICMA year fraction, walking the periods before and
+    /// after the reference period that overlap the dates.
+    fn icma(start: Date, end: Date, period_start: Date, period_end: Date, frequency: u8) -> f64 {
+        let frequency = frequency.max(1);
+        let months = (12 / frequency as i32).max(1);
+        let fraction = |from: Date, to: Date| match actual_days(from, to) {
+            0 => 0.0,
+            days => overlap(start, end, from, to) as f64 / (frequency as i64 * days) as f64,
+        };
+
+        // Periods next to a reference period ending on the last day of a
+        // month also end on the last day of a month.
+        let shift = |date: Date, periods: i32| {
+            let duration = CalendarDuration::months(periods.checked_mul(months)?);
+            let shifted = CalendarDuration::checked_date_add(date, duration)?;
+            match date.day() == days_in_year_month(date.year(), date.month()) {
+                true => shifted
+                    .replace_day(days_in_year_month(shifted.year(), shifted.month()))
+                    .ok(),
+                false => Some(shifted),
+            }
+        };
+
+        let mut sum = fraction(period_start, period_end);
+
+        let (mut periods, mut to) = (0, period_start);
+        while start < to {
+            periods -= 1;
+            let Some(from) = shift(period_start, periods) else {
+                break;
+            };
+
+            sum += fraction(from, to);
+            to = from;
+        }
+
+        let (mut periods, mut from) = (0, period_end);
+        while end > from {
+            periods += 1;
+            let Some(to) = shift(period_end, periods) else {
+                break;
+            };
+
+            sum += fraction(from, to);
+            from = to;
+        }
+
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::{Date, Month};
+
+    use crate::{BusinessCalendar, DayCount, WeekendMask};
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    fn assert_close(left: f64, right: f64) {
+        assert!((left - right).abs() < 1e-12, "{} != {}", left, right);
+    }
+
+    #[test]
+    fn thirty_360() {
+        // 2006 ISDA Definitions, section 4.16, 30/360 examples.
+        let cases = [
+            ((2007, January, 15), (2007, January, 30), [15, 15, 15]),
+            ((2007, January, 15), (2007, February, 15), [30, 30, 30]),
+            ((2007, January, 15), (2007, July, 15), [180, 180, 180]),
+            ((2007, September, 30), (2008, March, 31), [180, 180, 180]),
+            ((2007, September, 30), (2007, October, 31), [30, 30, 30]),
+            (
+                (2007, September, 30),
+                (2008, September, 30),
+                [360, 360, 360],
+            ),
+            ((2007, January, 31), (2007, February, 28), [28, 28, 30]),
+            ((2007, February, 28), (2007, August, 31), [180, 182, 180]),
+            ((2006, August, 31), (2007, February, 28), [178, 178, 180]),
+            ((2007, February, 28), (2008, February, 29), [360, 361, 360]),
+            ((2008, February, 29), (2009, February, 28), [360, 359, 360]),
+        ];
+
+        let isda = DayCount::Thirty360Isda { termination: None };
+        let conventions = [DayCount::Thirty360Us, DayCount::Thirty360European, isda];
+        for ((y1, m1, d1), (y2, m2, d2), expected) in cases {
+            let (start, end) = (date(y1, m1, d1), date(y2, m2, d2));
+            for (convention, expected) in conventions.iter().zip(expected) {
+                assert_eq!(convention.day_count(start, end), expected);
+                assert_eq!(convention.day_count(end, start), -expected);
+                assert_close(
+                    convention.year_fraction(start, end),
+                    expected as f64 / 360.0,
+                );
+            }
+        }
+
+        let (start, end) = (date(2007, February, 28), date(2008, February, 29));
+        let termination = Some(end);
+        let isda = DayCount::Thirty360Isda { termination };
+        assert_eq!(isda.day_count(start, end), 359);
+    }
+
+    #[test]
+    fn act_act() {
+        // ISDA, "EMU and market conventions: recent developments", 1998.
+        let cases = [
+            // Regular period.
+            (
+                (2003, November, 1),
+                (2004, May, 1),
+                (2003, November, 1),
+                (2004, May, 1),
+                2,
+            ),
+            // Short first period.
+            (
+                (1999, February, 1),
+                (1999, July, 1),
+                (1998, July, 1),
+                (1999, July, 1),
+                1,
+            ),
+            // Long first period.
+            (
+                (2002, August, 15),
+                (2003, July, 15),
+                (2003, January, 15),
+                (2003, July, 15),
+                2,
+            ),
+            // Short final period.
+            (
+                (2000, January, 30),
+                (2000, June, 30),
+                (2000, January, 30),
+                (2000, July, 30),
+                2,
+            ),
+            // Long final period.
+            (
+                (1999, November, 30),
+                (2000, April, 30),
+                (1999, November, 30),
+                (2000, February, 29),
+                4,
+            ),
+        ];
+
+        let expected = [
+            (0.497724380567, 0.5),
+            (0.410958904110, 0.410958904110),
+            (0.915068493151, 0.915760869565),
+            (0.415300546448, 0.417582417582),
+            (0.415540085336, 0.415760869565),
+        ];
+
+        for ((s, e, ps, pe, frequency), (isda, icma)) in cases.into_iter().zip(expected) {
+            let (start, end) = (date(s.0, s.1, s.2), date(e.0, e.1, e.2));
+            let period_start = date(ps.0, ps.1, ps.2);
+            let period_end = date(pe.0, pe.1, pe.2);
+            let convention = DayCount::ActActIcma {
+                period_start,
+                period_end,
+                frequency,
+            };
+
+            assert!((DayCount::ActActIsda.year_fraction(start, end) - isda).abs() < 1e-11);
+            assert!((convention.year_fraction(start, end) - icma).abs() < 1e-11);
+            assert_close(
+                convention.year_fraction(end, start),
+                -convention.year_fraction(start, end),
+            );
+        }
+    }
+
+    #[test]
+    fn actual() {
+        let (start, end) = (date(2024, January, 15), date(2024, July, 15));
+        assert_eq!(DayCount::Act360.day_count(start, end), 182);
+        assert_close(DayCount::Act360.year_fraction(start, end), 182.0 / 360.0);
+        assert_close(
+            DayCount::Act365Fixed.year_fraction(start, end),
+            182.0 / 365.0,
+        );
+        assert_close(
+            DayCount::ActActIsda.year_fraction(start, end),
+            182.0 / 366.0,
+        );
+
+        let calendar = BusinessCalendar::new(WeekendMask::SATURDAY_SUNDAY)
+            .with_holiday(date(2024, January, 25))
+            .with_holiday(date(2024, February, 12));
+        let business = DayCount::Business252 {
+            calendar: &calendar,
+        };
+
+        let (start, end) = (date(2024, January, 15), date(2024, February, 15));
+        assert_eq!(business.day_count(start, end), 21);
+        assert_close(business.year_fraction(start, end), 21.0 / 252.0);
+        assert_eq!(business.day_count(end, start), -21);
+    }
+}
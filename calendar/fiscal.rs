@@ -0,0 +1,338 @@
+use time::{Date, Month};
+
+use crate::CalendarDuration;
+
+/// The convention used to name a fiscal year.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FiscalYearNaming {
+    /// The fiscal year is named by the calendar year it starts in, e.g. the UK tax year.
+    #[default]
+    StartYear,
+    /// The fiscal year is named by the calendar year it ends in, e.g. the US federal fiscal year.
+    EndYear,
+}
+
+/// A fiscal year that starts on a fixed day of a fixed month.
+///
+/// A fiscal year consists of 12 periods of one month and 4 quarters of three
+/// months, each starting on the same day of the month as the fiscal year.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::{FiscalCalendar, FiscalYearNaming};
+/// let calendar = FiscalCalendar::new(October, 1, FiscalYearNaming::EndYear).unwrap();
+/// let d0 = Date::from_calendar_date(2023, November, 15).unwrap();
+/// assert_eq!(calendar.fiscal_year(d0), 2024);
+/// assert_eq!(calendar.fiscal_quarter(d0), 1);
+/// assert_eq!(calendar.fiscal_period(d0), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FiscalCalendar {
+    month: Month,
+    day: u8,
+    naming: FiscalYearNaming,
+}
+
+impl FiscalCalendar {
+    /// Creates a new `FiscalCalendar` starting on the provided month and day.
+    ///
+    /// Returns `None` if the day is not in `1..=28`, as every period must start
+    /// on a day that exists in every month.
+    ///
+    /// ```rust
+    /// # use time::Month::*;
+    /// # use timext::{FiscalCalendar, FiscalYearNaming};
+    /// assert!(FiscalCalendar::new(April, 6, FiscalYearNaming::StartYear).is_some());
+    /// assert!(FiscalCalendar::new(January, 31, FiscalYearNaming::StartYear).is_none());
+    /// ```
+    #[must_use]
+    pub const fn new(month: Month, day: u8, naming: FiscalYearNaming) -> Option<Self> {
+        match day {
+            1..=28 => Some(Self { month, day, naming }),
+            _ => None,
+        }
+    }
+
+    /// Returns the first month of the fiscal year.
+    #[must_use]
+    pub const fn start_month(&self) -> Month {
+        self.month
+    }
+
+    /// Returns the day of the month the fiscal year starts on.
+    #[must_use]
+    pub const fn start_day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the naming convention of the fiscal year.
+    #[must_use]
+    pub const fn naming(&self) -> FiscalYearNaming {
+        self.naming
+    }
+}
+
+impl FiscalCalendar {
+    /// Returns the calendar year the fiscal year named `year` starts in.
+    const fn start_year(&self, year: i32) -> i32 {
+        match self.naming {
+            FiscalYearNaming::EndYear if !self.starts_with_calendar_year() => year - 1,
+            _ => year,
+        }
+    }
+
+    const fn starts_with_calendar_year(&self) -> bool {
+        matches!(self.month, Month::January) && self.day == 1
+    }
+
+    /// Returns the number of whole periods between the start of the fiscal year
+    /// that starts in the calendar year of the date and the date.
+    fn periods_since_start(&self, date: Date) -> i32 {
+        let months = date.month() as i32 - self.month as i32;
+        match date.day() < self.day {
+            true => months - 1,
+            false => months,
+        }
+    }
+
+    /// Returns the name of the fiscal year that contains the date.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{FiscalCalendar, FiscalYearNaming};
+    /// let calendar = FiscalCalendar::new(April, 6, FiscalYearNaming::StartYear).unwrap();
+    /// let d0 = Date::from_calendar_date(2024, April, 5).unwrap();
+    /// assert_eq!(calendar.fiscal_year(d0), 2023);
+    /// assert_eq!(calendar.fiscal_year(d0.next_day().unwrap()), 2024);
+    /// ```
+    #[must_use]
+    pub fn fiscal_year(&self, date: Date) -> i32 {
+        let year = match self.periods_since_start(date) {
+            x if x.is_negative() => date.year() - 1,
+            _ => date.year(),
+        };
+
+        match self.naming {
+            FiscalYearNaming::EndYear if !self.starts_with_calendar_year() => year + 1,
+            _ => year,
+        }
+    }
+
+    /// Returns the fiscal period, in `1..=12`, that contains the date.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{FiscalCalendar, FiscalYearNaming};
+    /// let calendar = FiscalCalendar::new(April, 6, FiscalYearNaming::StartYear).unwrap();
+    /// let d0 = Date::from_calendar_date(2024, January, 5).unwrap();
+    /// assert_eq!(calendar.fiscal_period(d0), 9);
+    /// ```
+    #[must_use]
+    pub fn fiscal_period(&self, date: Date) -> u8 {
+        self.periods_since_start(date).rem_euclid(12) as u8 + 1
+    }
+
+    /// Returns the fiscal quarter, in `1..=4`, that contains the date.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{FiscalCalendar, FiscalYearNaming};
+    /// let calendar = FiscalCalendar::new(July, 1, FiscalYearNaming::EndYear).unwrap();
+    /// let d0 = Date::from_calendar_date(2024, March, 31).unwrap();
+    /// assert_eq!(calendar.fiscal_quarter(d0), 3);
+    /// ```
+    #[must_use]
+    pub fn fiscal_quarter(&self, date: Date) -> u8 {
+        (self.fiscal_period(date) - 1) / 3 + 1
+    }
+}
+
+impl FiscalCalendar {
+    /// Returns the first day of the fiscal year.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{FiscalCalendar, FiscalYearNaming};
+    /// let calendar = FiscalCalendar::new(October, 1, FiscalYearNaming::EndYear).unwrap();
+    /// let d0 = Date::from_calendar_date(2023, October, 1).unwrap();
+    /// assert_eq!(calendar.year_start(2024), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn year_start(&self, year: i32) -> Option<Date> {
+        self.period_start(year, 1)
+    }
+
+    /// Returns the last day of the fiscal year.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{FiscalCalendar, FiscalYearNaming};
+    /// let calendar = FiscalCalendar::new(October, 1, FiscalYearNaming::EndYear).unwrap();
+    /// let d0 = Date::from_calendar_date(2024, September, 30).unwrap();
+    /// assert_eq!(calendar.year_end(2024), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn year_end(&self, year: i32) -> Option<Date> {
+        self.period_end(year, 12)
+    }
+
+    /// Returns the first day of the fiscal quarter, in `1..=4`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{FiscalCalendar, FiscalYearNaming};
+    /// let calendar = FiscalCalendar::new(April, 6, FiscalYearNaming::StartYear).unwrap();
+    /// let d0 = Date::from_calendar_date(2024, January, 6).unwrap();
+    /// assert_eq!(calendar.quarter_start(2023, 4), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn quarter_start(&self, year: i32, quarter: u8) -> Option<Date> {
+        match quarter {
+            1..=4 => self.period_start(year, quarter * 3 - 2),
+            _ => None,
+        }
+    }
+
+    /// Returns the last day of the fiscal quarter, in `1..=4`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{FiscalCalendar, FiscalYearNaming};
+    /// let calendar = FiscalCalendar::new(April, 6, FiscalYearNaming::StartYear).unwrap();
+    /// let d0 = Date::from_calendar_date(2024, April, 5).unwrap();
+    /// assert_eq!(calendar.quarter_end(2023, 4), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn quarter_end(&self, year: i32, quarter: u8) -> Option<Date> {
+        match quarter {
+            1..=4 => self.period_end(year, quarter * 3),
+            _ => None,
+        }
+    }
+
+    /// Returns the first day of the fiscal period, in `1..=12`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{FiscalCalendar, FiscalYearNaming};
+    /// let calendar = FiscalCalendar::new(April, 6, FiscalYearNaming::StartYear).unwrap();
+    /// let d0 = Date::from_calendar_date(2024, March, 6).unwrap();
+    /// assert_eq!(calendar.period_start(2023, 12), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn period_start(&self, year: i32, period: u8) -> Option<Date> {
+        if !(1..=12).contains(&period) {
+            return None;
+        }
+
+        let start = Date::from_calendar_date(self.start_year(year), self.month, self.day).ok()?;
+        let months = CalendarDuration::months(period as i32 - 1);
+        CalendarDuration::checked_date_add(start, months)
+    }
+
+    /// Returns the last day of the fiscal period, in `1..=12`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{FiscalCalendar, FiscalYearNaming};
+    /// let calendar = FiscalCalendar::new(January, 1, FiscalYearNaming::EndYear).unwrap();
+    /// let d0 = Date::from_calendar_date(2024, February, 29).unwrap();
+    /// assert_eq!(calendar.period_end(2024, 2), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn period_end(&self, year: i32, period: u8) -> Option<Date> {
+        let start = self.period_start(year, period)?;
+        CalendarDuration::checked_date_add(start, CalendarDuration::months(1))?.previous_day()
+    }
+}
+
+impl FiscalCalendar {
+    /// Shifts the date by the provided number of fiscal quarters.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{FiscalCalendar, FiscalYearNaming};
+    /// let calendar = FiscalCalendar::new(April, 6, FiscalYearNaming::StartYear).unwrap();
+    /// let d0 = Date::from_calendar_date(2024, March, 31).unwrap();
+    /// let d1 = calendar.checked_add_quarters(d0, 1).unwrap();
+    /// assert_eq!(d1, Date::from_calendar_date(2024, June, 30).unwrap());
+    /// assert_eq!(calendar.fiscal_quarter(d1), 1);
+    /// ```
+    #[must_use]
+    pub fn checked_add_quarters(&self, date: Date, quarters: i32) -> Option<Date> {
+        let months = quarters.checked_mul(3)?;
+        CalendarDuration::checked_date_add(date, CalendarDuration::months(months))
+    }
+
+    /// Shifts the date by the provided number of fiscal years.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{FiscalCalendar, FiscalYearNaming};
+    /// let calendar = FiscalCalendar::new(October, 1, FiscalYearNaming::EndYear).unwrap();
+    /// let d0 = Date::from_calendar_date(2024, February, 29).unwrap();
+    /// let d1 = calendar.checked_add_years(d0, 1).unwrap();
+    /// assert_eq!(d1, Date::from_calendar_date(2025, February, 28).unwrap());
+    /// assert_eq!(calendar.fiscal_year(d1), 2025);
+    /// ```
+    #[must_use]
+    pub fn checked_add_years(&self, date: Date, years: i32) -> Option<Date> {
+        let months = years.checked_mul(12)?;
+        CalendarDuration::checked_date_add(date, CalendarDuration::months(months))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Date;
+    use time::Month::*;
+
+    use crate::{FiscalCalendar, FiscalYearNaming};
+
+    #[test]
+    fn uk_tax_year() {
+        let calendar = FiscalCalendar::new(April, 6, FiscalYearNaming::StartYear).unwrap();
+        let d0 = Date::from_calendar_date(2023, April, 6).unwrap();
+        let d1 = Date::from_calendar_date(2024, April, 5).unwrap();
+        assert_eq!(calendar.year_start(2023), Some(d0));
+        assert_eq!(calendar.year_end(2023), Some(d1));
+        assert_eq!(calendar.fiscal_year(d0), 2023);
+        assert_eq!(calendar.fiscal_year(d1), 2023);
+        assert_eq!(calendar.fiscal_period(d0), 1);
+        assert_eq!(calendar.fiscal_period(d1), 12);
+        assert_eq!(calendar.fiscal_quarter(d1), 4);
+    }
+
+    #[test]
+    fn calendar_year() {
+        let calendar = FiscalCalendar::new(January, 1, FiscalYearNaming::EndYear).unwrap();
+        let d0 = Date::from_calendar_date(2023, January, 1).unwrap();
+        let d1 = Date::from_calendar_date(2023, December, 31).unwrap();
+        assert_eq!(calendar.fiscal_year(d0), 2023);
+        assert_eq!(calendar.fiscal_year(d1), 2023);
+        assert_eq!(calendar.year_start(2023), Some(d0));
+        assert_eq!(calendar.year_end(2023), Some(d1));
+    }
+
+    #[test]
+    fn periods_cover_year() {
+        let calendar = FiscalCalendar::new(July, 15, FiscalYearNaming::EndYear).unwrap();
+        let mut date = calendar.year_start(2024).unwrap();
+        let end = calendar.year_end(2024).unwrap();
+        while date <= end {
+            let period = calendar.fiscal_period(date);
+            assert_eq!(calendar.fiscal_year(date), 2024);
+            assert!(calendar.period_start(2024, period).unwrap() <= date);
+            assert!(calendar.period_end(2024, period).unwrap() >= date);
+            date = date.next_day().unwrap();
+        }
+    }
+
+    #[test]
+    fn invalid_period() {
+        let calendar = FiscalCalendar::new(April, 1, FiscalYearNaming::StartYear).unwrap();
+        assert_eq!(calendar.period_start(2023, 0), None);
+        assert_eq!(calendar.period_start(2023, 13), None);
+        assert_eq!(calendar.quarter_end(2023, 5), None);
+    }
+}
@@ -0,0 +1,199 @@
+use time::Month::*;
+use time::Weekday::*;
+
+use super::rule::{easter, fixed, once};
+use crate::{HolidayDate, HolidayRule, Observance::*};
+
+/// A state of Germany.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GermanState {
+    /// Baden-Württemberg (BW).
+    BadenWurttemberg,
+    /// Bavaria (BY).
+    Bavaria,
+    /// Berlin (BE).
+    Berlin,
+    /// Brandenburg (BB).
+    Brandenburg,
+    /// Bremen (HB).
+    Bremen,
+    /// Hamburg (HH).
+    Hamburg,
+    /// Hesse (HE).
+    Hesse,
+    /// Lower Saxony (NI).
+    LowerSaxony,
+    /// Mecklenburg-Vorpommern (MV).
+    MecklenburgVorpommern,
+    /// North Rhine-Westphalia (NW).
+    NorthRhineWestphalia,
+    /// Rhineland-Palatinate (RP).
+    RhinelandPalatinate,
+    /// Saarland (SL).
+    Saarland,
+    /// Saxony (SN).
+    Saxony,
+    /// Saxony-Anhalt (ST).
+    SaxonyAnhalt,
+    /// Schleswig-Holstein (SH).
+    SchleswigHolstein,
+    /// Thuringia (TH).
+    Thuringia,
+}
+
+/// Returns the public holidays of the state, excluding the holidays of
+/// individual municipalities, e.g. Assumption Day in parts of Bavaria.
+pub(super) fn rules(state: GermanState) -> Vec<HolidayRule> {
+    use GermanState::*;
+
+    let mut rules = vec![
+        fixed("Neujahr", January, 1, Actual),
+        easter("Karfreitag", -2),
+        easter("Ostermontag", 1),
+        fixed("Tag der Arbeit", May, 1, Actual),
+        easter("Christi Himmelfahrt", 39),
+        easter("Pfingstmontag", 50),
+        fixed("Tag der Deutschen Einheit", October, 3, Actual),
+        fixed("1. Weihnachtstag", December, 25, Actual),
+        fixed("2. Weihnachtstag", December, 26, Actual),
+    ];
+
+    if matches!(state, BadenWurttemberg | Bavaria | SaxonyAnhalt) {
+        rules.push(fixed("Heilige Drei Könige", January, 6, Actual));
+    }
+
+    if state == Berlin {
+        let frauentag = fixed("Internationaler Frauentag", March, 8, Actual);
+        rules.push(frauentag.with_start_year(2019));
+        rules.push(once("Tag der Befreiung", 2020, May, 8));
+        rules.push(once("Tag der Befreiung", 2025, May, 8));
+    }
+
+    if state == MecklenburgVorpommern {
+        let frauentag = fixed("Internationaler Frauentag", March, 8, Actual);
+        rules.push(frauentag.with_start_year(2023));
+    }
+
+    if state == Brandenburg {
+        rules.push(easter("Ostersonntag", 0));
+        rules.push(easter("Pfingstsonntag", 49));
+    }
+
+    if matches!(
+        state,
+        BadenWurttemberg | Bavaria | Hesse | NorthRhineWestphalia | RhinelandPalatinate | Saarland
+    ) {
+        rules.push(easter("Fronleichnam", 60));
+    }
+
+    if state == Saarland {
+        rules.push(fixed("Mariä Himmelfahrt", August, 15, Actual));
+    }
+
+    if state == Thuringia {
+        let weltkindertag = fixed("Weltkindertag", September, 20, Actual);
+        rules.push(weltkindertag.with_start_year(2019));
+    }
+
+    let reformationstag = fixed("Reformationstag", October, 31, Actual);
+    match state {
+        Brandenburg | MecklenburgVorpommern | Saxony | SaxonyAnhalt | Thuringia => {
+            rules.push(reformationstag);
+        }
+        Bremen | Hamburg | LowerSaxony | SchleswigHolstein => {
+            rules.push(once("Reformationstag", 2017, October, 31));
+            rules.push(reformationstag.with_start_year(2018));
+        }
+        _ => rules.push(once("Reformationstag", 2017, October, 31)),
+    }
+
+    if matches!(
+        state,
+        BadenWurttemberg | Bavaria | NorthRhineWestphalia | RhinelandPalatinate | Saarland
+    ) {
+        rules.push(fixed("Allerheiligen", November, 1, Actual));
+    }
+
+    if state == Saxony {
+        let date = HolidayDate::OnOrAfter {
+            month: November,
+            day: 16,
+            weekday: Wednesday,
+        };
+        rules.push(HolidayRule::new("Buß- und Bettag", date));
+    }
+
+    rules
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+
+    use super::super::tests::assert_year;
+    use crate::{GermanState, HolidayDataset};
+
+    #[test]
+    fn states() {
+        let dataset = HolidayDataset::Germany(GermanState::Bavaria);
+        let dates = [
+            (January, 1),
+            (March, 29),
+            (April, 1),
+            (May, 1),
+            (May, 9),
+            (May, 20),
+            (May, 30),
+            (October, 3),
+            (November, 1),
+            (December, 25),
+            (December, 26),
+        ];
+        assert_year(dataset, 2024, &dates);
+
+        let dataset = HolidayDataset::Germany(GermanState::Saxony);
+        let dates = [
+            (January, 1),
+            (March, 29),
+            (April, 1),
+            (May, 1),
+            (May, 9),
+            (May, 20),
+            (October, 3),
+            (October, 31),
+            (November, 20),
+            (December, 25),
+            (December, 26),
+        ];
+        assert_year(dataset, 2024, &dates);
+
+        let dataset = HolidayDataset::Germany(GermanState::Berlin);
+        let dates = [
+            (January, 1),
+            (April, 18),
+            (April, 21),
+            (May, 1),
+            (May, 8),
+            (May, 29),
+            (June, 9),
+            (October, 3),
+            (December, 25),
+            (December, 26),
+        ];
+        assert_year(dataset, 2025, &dates);
+
+        let dataset = HolidayDataset::Germany(GermanState::LowerSaxony);
+        let dates = [
+            (April, 14),
+            (April, 17),
+            (May, 1),
+            (May, 25),
+            (June, 5),
+            (October, 3),
+            (October, 31),
+            (December, 25),
+            (December, 26),
+        ];
+        assert_year(dataset, 2017, &dates);
+    }
+}
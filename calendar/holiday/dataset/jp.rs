@@ -0,0 +1,148 @@
+use time::Month::*;
+use time::Weekday::*;
+use time::{Date, Month};
+
+use super::rule::{except, fixed, nth, once};
+use crate::{HolidayRule, Observance::*};
+
+/// Returns the day of the equinox in the month, from the formula of the
+/// National Astronomical Observatory of Japan for the years 1980 to 2099.
+fn equinox(year: i32, month: Month) -> u8 {
+    let base = match month {
+        March => 20_843_100,
+        _ => 23_248_800,
+    };
+
+    let years = (year - 1980) as i64;
+    ((base + 242_194 * years) / 1_000_000 - years / 4) as u8
+}
+
+/// Returns the national holidays of Japan, including substitute holidays and
+/// the citizens' holidays between two other holidays.
+pub(super) fn rules() -> Vec<HolidayRule> {
+    let mut rules = vec![
+        fixed("元日", January, 1, SundayToNextDay),
+        nth("成人の日", January, Monday, 2),
+        fixed("建国記念の日", February, 11, SundayToNextDay),
+        fixed("天皇誕生日", December, 23, SundayToNextDay).with_end_year(2018),
+        fixed("天皇誕生日", February, 23, SundayToNextDay).with_start_year(2020),
+        fixed("昭和の日", April, 29, SundayToNextDay),
+        fixed("憲法記念日", May, 3, SundayToNextDay),
+        fixed("みどりの日", May, 4, SundayToNextDay),
+        fixed("こどもの日", May, 5, SundayToNextDay),
+        nth("敬老の日", September, Monday, 3),
+        fixed("文化の日", November, 3, SundayToNextDay),
+        fixed("勤労感謝の日", November, 23, SundayToNextDay),
+        once("国民の休日", 2019, April, 30),
+        once("即位の日", 2019, May, 1),
+        once("国民の休日", 2019, May, 2),
+        once("即位礼正殿の儀の行われる日", 2019, October, 22),
+        once("海の日", 2020, July, 23),
+        once("スポーツの日", 2020, July, 24),
+        once("山の日", 2020, August, 10),
+        once("海の日", 2021, July, 22),
+        once("スポーツの日", 2021, July, 23),
+        once("山の日", 2021, August, 8).with_observance(SundayToNextDay),
+    ];
+
+    rules.extend(except(nth("海の日", July, Monday, 3), &[2020, 2021]));
+    let mountain = fixed("山の日", August, 11, SundayToNextDay).with_start_year(2016);
+    rules.extend(except(mountain, &[2020, 2021]));
+    rules.extend(except(
+        nth("スポーツの日", October, Monday, 2),
+        &[2020, 2021],
+    ));
+
+    for year in 2007..=2099 {
+        let vernal = once("春分の日", year, March, equinox(year, March));
+        let day = equinox(year, September);
+        let autumnal = once("秋分の日", year, September, day);
+        rules.push(vernal.with_observance(SundayToNextDay));
+        rules.push(autumnal.with_observance(SundayToNextDay));
+
+        // A day between Respect for the Aged Day and the equinox.
+        let date = Date::from_calendar_date(year, September, day).unwrap();
+        if date.weekday() == Wednesday && (15..=21).contains(&(day - 2)) {
+            rules.push(once("国民の休日", year, September, day - 1));
+        }
+    }
+
+    let start = |x: HolidayRule| match x.start_year() {
+        Some(_) => x,
+        None => x.with_start_year(2007),
+    };
+
+    rules.into_iter().map(start).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+
+    use super::super::tests::assert_year;
+    use crate::HolidayDataset;
+
+    #[test]
+    fn japan() {
+        let dataset = HolidayDataset::Japan;
+        let dates = [
+            (January, 1),
+            (January, 8),
+            (February, 12),
+            (February, 23),
+            (March, 20),
+            (April, 29),
+            (May, 3),
+            (May, 6),
+            (July, 15),
+            (August, 12),
+            (September, 16),
+            (September, 23),
+            (October, 14),
+            (November, 4),
+        ];
+        assert_year(dataset, 2024, &dates);
+
+        let dates = [
+            (January, 1),
+            (January, 14),
+            (February, 11),
+            (March, 21),
+            (April, 29),
+            (April, 30),
+            (May, 1),
+            (May, 2),
+            (May, 3),
+            (May, 6),
+            (July, 15),
+            (August, 12),
+            (September, 16),
+            (September, 23),
+            (October, 14),
+            (October, 22),
+            (November, 4),
+        ];
+        assert_year(dataset, 2019, &dates);
+
+        let dates = [
+            (January, 1),
+            (January, 12),
+            (February, 11),
+            (February, 23),
+            (March, 20),
+            (April, 29),
+            (May, 4),
+            (May, 5),
+            (May, 6),
+            (July, 20),
+            (August, 11),
+            (September, 21),
+            (September, 22),
+            (September, 23),
+            (October, 12),
+            (November, 3),
+            (November, 23),
+        ];
+        assert_year(dataset, 2026, &dates);
+    }
+}
@@ -0,0 +1,193 @@
+use std::ops::RangeInclusive;
+
+use crate::{BusinessCalendar, HolidaySet};
+
+#[cfg(feature = "holidays-de")]
+mod de;
+#[cfg(feature = "holidays-de")]
+pub use de::GermanState;
+#[cfg(feature = "holidays-jp")]
+mod jp;
+#[cfg(feature = "holidays-nyse")]
+mod nyse;
+#[cfg(feature = "holidays-target2")]
+mod target2;
+#[cfg(feature = "holidays-uk")]
+mod uk;
+#[cfg(feature = "holidays-us")]
+mod us;
+
+/// A built-in set of holiday rules for a jurisdiction or a market.
+///
+/// Each dataset is enabled by its own `holidays-*` feature, and all of them
+/// by the `holidays` feature. The weekend of every dataset is Saturday and
+/// Sunday, and holidays that fall on it are included with their observance.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::HolidayDataset;
+/// let calendar = HolidayDataset::Target2.calendar(2024..=2024);
+/// let d0 = Date::from_calendar_date(2024, March, 29).unwrap();
+/// let d1 = Date::from_calendar_date(2024, April, 2).unwrap();
+/// assert_eq!(calendar.next_business_day(d0), Some(d1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HolidayDataset {
+    /// The federal holidays of the United States, from 1971.
+    #[cfg(feature = "holidays-us")]
+    UsFederal,
+    /// The holidays and early closes of the New York Stock Exchange, from 1998.
+    #[cfg(feature = "holidays-nyse")]
+    Nyse,
+    /// The bank holidays of England and Wales, from 1978.
+    #[cfg(feature = "holidays-uk")]
+    EnglandWales,
+    /// The bank holidays of Scotland, from 1978.
+    #[cfg(feature = "holidays-uk")]
+    Scotland,
+    /// The closing days of the TARGET2 payment system, from 2000.
+    #[cfg(feature = "holidays-target2")]
+    Target2,
+    /// The statewide public holidays of a German state, from 1995.
+    #[cfg(feature = "holidays-de")]
+    Germany(GermanState),
+    /// The national holidays of Japan, from 2007 to 2099.
+    #[cfg(feature = "holidays-jp")]
+    Japan,
+}
+
+impl HolidayDataset {
+    /// Returns the revision of the dataset, incremented whenever its rules change.
+    #[must_use]
+    pub const fn version(self) -> u16 {
+        match self {
+            #[cfg(feature = "holidays-us")]
+            Self::UsFederal => 1,
+            #[cfg(feature = "holidays-nyse")]
+            Self::Nyse => 1,
+            #[cfg(feature = "holidays-uk")]
+            Self::EnglandWales | Self::Scotland => 1,
+            #[cfg(feature = "holidays-target2")]
+            Self::Target2 => 1,
+            #[cfg(feature = "holidays-de")]
+            Self::Germany(_) => 1,
+            #[cfg(feature = "holidays-jp")]
+            Self::Japan => 1,
+        }
+    }
+
+    /// Returns the holiday rules of the dataset.
+    #[must_use]
+    pub fn rules(self) -> HolidaySet {
+        let rules = match self {
+            #[cfg(feature = "holidays-us")]
+            Self::UsFederal => us::federal(),
+            #[cfg(feature = "holidays-nyse")]
+            Self::Nyse => nyse::holidays(),
+            #[cfg(feature = "holidays-uk")]
+            Self::EnglandWales => uk::england_wales(),
+            #[cfg(feature = "holidays-uk")]
+            Self::Scotland => uk::scotland(),
+            #[cfg(feature = "holidays-target2")]
+            Self::Target2 => target2::rules(),
+            #[cfg(feature = "holidays-de")]
+            Self::Germany(state) => de::rules(state),
+            #[cfg(feature = "holidays-jp")]
+            Self::Japan => jp::rules(),
+        };
+
+        HolidaySet::new().with_rules(rules)
+    }
+
+    /// Returns the rules of the half days of the dataset, e.g. early closes.
+    #[must_use]
+    pub fn half_days(self) -> HolidaySet {
+        match self {
+            #[cfg(feature = "holidays-nyse")]
+            Self::Nyse => HolidaySet::new().with_rules(nyse::early_closes()),
+            #[allow(unreachable_patterns)]
+            _ => HolidaySet::new(),
+        }
+    }
+
+    /// Returns a [`BusinessCalendar`] with the holidays and half days of the years.
+    ///
+    /// Half days that fall on a weekend or a holiday are left out, e.g. the
+    /// early close before Independence Day when the holiday is observed on it.
+    #[must_use]
+    pub fn calendar(self, years: RangeInclusive<i32>) -> BusinessCalendar {
+        let calendar = BusinessCalendar::default().with_holidays(self.rules().dates(years.clone()));
+        let half_days = self.half_days().dates(years);
+        let half_days: Vec<_> = half_days
+            .into_iter()
+            .filter(|x| calendar.is_business_day(*x))
+            .collect();
+        calendar.with_half_days(half_days)
+    }
+}
+
+/// Shorthands for the rules of the datasets, not all of which every dataset uses.
+#[cfg_attr(not(feature = "holidays"), allow(dead_code))]
+mod rule {
+    use time::{Month, Weekday};
+
+    use crate::{Easter, HolidayDate, HolidayRule, Observance};
+
+    pub(super) fn fixed(name: &str, month: Month, day: u8, observance: Observance) -> HolidayRule {
+        HolidayRule::new(name, HolidayDate::Fixed { month, day }).with_observance(observance)
+    }
+
+    pub(super) fn nth(name: &str, month: Month, weekday: Weekday, n: i8) -> HolidayRule {
+        HolidayRule::new(name, HolidayDate::NthWeekday { month, weekday, n })
+    }
+
+    pub(super) fn easter(name: &str, offset: i16) -> HolidayRule {
+        let easter = Easter::Western;
+        HolidayRule::new(name, HolidayDate::Easter { easter, offset })
+    }
+
+    /// Returns a holiday observed in a single year.
+    pub(super) fn once(name: &str, year: i32, month: Month, day: u8) -> HolidayRule {
+        let rule = HolidayRule::new(name, HolidayDate::Fixed { month, day });
+        rule.with_start_year(year).with_end_year(year)
+    }
+
+    /// Splits the rule into rules that skip the provided years, in ascending order.
+    pub(super) fn except(rule: HolidayRule, years: &[i32]) -> Vec<HolidayRule> {
+        let mut rules = Vec::new();
+        let mut rule = rule;
+        for &year in years {
+            rules.push(rule.clone().with_end_year(year - 1));
+            rule = rule.with_start_year(year + 1);
+        }
+
+        rules.push(rule);
+        rules
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use crate::HolidayDataset;
+
+    /// Asserts that the holidays on weekdays in the year are exactly the dates.
+    pub(super) fn assert_year(dataset: HolidayDataset, year: i32, dates: &[(Month, u8)]) {
+        let expected: Vec<_> = dates
+            .iter()
+            .map(|&(m, d)| Date::from_calendar_date(year, m, d).unwrap())
+            .collect();
+
+        let calendar = dataset.calendar(year - 1..=year + 1);
+        let holidays: Vec<_> = calendar
+            .holidays()
+            .iter()
+            .copied()
+            .filter(|x| x.year() == year && !calendar.weekend().contains(x.weekday()))
+            .collect();
+
+        assert_eq!(holidays, expected, "{:?} {}", dataset, year);
+    }
+}
@@ -0,0 +1,110 @@
+use time::Month::*;
+use time::Weekday::*;
+
+use super::rule::{easter, fixed, nth, once};
+use crate::{HolidayDate, HolidayRule, Observance::*};
+
+/// Returns the holidays and the unscheduled closings of the NYSE.
+pub(super) fn holidays() -> Vec<HolidayRule> {
+    vec![
+        // New Year's Day on Saturday is not observed, as the last trading
+        // day of the year would be lost.
+        fixed("New Year's Day", January, 1, SundayToNextDay),
+        nth("Martin Luther King, Jr. Day", January, Monday, 3),
+        nth("Washington's Birthday", February, Monday, 3),
+        easter("Good Friday", -2),
+        nth("Memorial Day", May, Monday, -1),
+        fixed(
+            "Juneteenth National Independence Day",
+            June,
+            19,
+            NearestWeekday,
+        )
+        .with_start_year(2022),
+        fixed("Independence Day", July, 4, NearestWeekday),
+        nth("Labor Day", September, Monday, 1),
+        nth("Thanksgiving Day", November, Thursday, 4),
+        fixed("Christmas Day", December, 25, NearestWeekday),
+        once("September 11 attacks", 2001, September, 11),
+        once("September 11 attacks", 2001, September, 12),
+        once("September 11 attacks", 2001, September, 13),
+        once("September 11 attacks", 2001, September, 14),
+        once("Day of mourning for Ronald Reagan", 2004, June, 11),
+        once("Day of mourning for Gerald Ford", 2007, January, 2),
+        once("Hurricane Sandy", 2012, October, 29),
+        once("Hurricane Sandy", 2012, October, 30),
+        once("Day of mourning for George H. W. Bush", 2018, December, 5),
+        once("Day of mourning for Jimmy Carter", 2025, January, 9),
+    ]
+}
+
+/// Returns the early closes of the NYSE, at 1:00 p.m.
+pub(super) fn early_closes() -> Vec<HolidayRule> {
+    let black_friday = HolidayDate::OnOrAfter {
+        month: November,
+        day: 23,
+        weekday: Friday,
+    };
+
+    vec![
+        fixed("Day before Independence Day", July, 3, Actual),
+        HolidayRule::new("Day after Thanksgiving", black_friday),
+        fixed("Christmas Eve", December, 24, Actual),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Date;
+    use time::Month::*;
+
+    use super::super::tests::assert_year;
+    use crate::HolidayDataset;
+
+    #[test]
+    fn nyse() {
+        let dataset = HolidayDataset::Nyse;
+        let dates = [
+            (January, 1),
+            (January, 9),
+            (January, 20),
+            (February, 17),
+            (April, 18),
+            (May, 26),
+            (June, 19),
+            (July, 4),
+            (September, 1),
+            (November, 27),
+            (December, 25),
+        ];
+        assert_year(dataset, 2025, &dates);
+
+        let dates = [
+            (January, 17),
+            (February, 21),
+            (April, 15),
+            (May, 30),
+            (June, 20),
+            (July, 4),
+            (September, 5),
+            (November, 24),
+            (December, 26),
+        ];
+        assert_year(dataset, 2022, &dates);
+
+        let calendar = dataset.calendar(2024..=2024);
+        let half_days: Vec<_> = [(July, 3), (November, 29), (December, 24)]
+            .iter()
+            .map(|&(m, d)| Date::from_calendar_date(2024, m, d).unwrap())
+            .collect();
+        assert_eq!(calendar.half_days(), half_days);
+
+        // Independence Day is observed on July 3, 2026, so there is no early close.
+        let calendar = dataset.calendar(2026..=2026);
+        let half_days: Vec<_> = [(November, 27), (December, 24)]
+            .iter()
+            .map(|&(m, d)| Date::from_calendar_date(2026, m, d).unwrap())
+            .collect();
+        assert_eq!(calendar.half_days(), half_days);
+    }
+}
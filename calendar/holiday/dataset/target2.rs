@@ -0,0 +1,50 @@
+use time::Month::*;
+
+use super::rule::{easter, fixed};
+use crate::{HolidayRule, Observance::*};
+
+/// Returns the closing days of TARGET2, and of TARGET before 2008.
+pub(super) fn rules() -> Vec<HolidayRule> {
+    vec![
+        fixed("New Year's Day", January, 1, Actual),
+        easter("Good Friday", -2),
+        easter("Easter Monday", 1),
+        fixed("Labour Day", May, 1, Actual),
+        fixed("Christmas Day", December, 25, Actual),
+        fixed("Boxing Day", December, 26, Actual),
+        fixed("New Year's Eve", December, 31, Actual).with_end_year(2001),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+
+    use super::super::tests::assert_year;
+    use crate::HolidayDataset;
+
+    #[test]
+    fn target2() {
+        let dataset = HolidayDataset::Target2;
+        let dates = [
+            (January, 1),
+            (March, 29),
+            (April, 1),
+            (May, 1),
+            (December, 25),
+            (December, 26),
+        ];
+        assert_year(dataset, 2024, &dates);
+
+        let dates = [
+            (January, 1),
+            (April, 13),
+            (April, 16),
+            (May, 1),
+            (December, 25),
+            (December, 26),
+            (December, 31),
+        ];
+        assert_year(dataset, 2001, &dates);
+    }
+}
@@ -0,0 +1,139 @@
+use time::Month::*;
+use time::Weekday::*;
+
+use super::rule::{easter, except, fixed, nth, once};
+use crate::{HolidayRule, Observance::*};
+
+/// Returns the bank holidays shared by England and Wales, and Scotland.
+fn common() -> Vec<HolidayRule> {
+    let mut rules = vec![
+        easter("Good Friday", -2),
+        fixed("Christmas Day", December, 25, NextWeekday),
+        fixed("Boxing Day", December, 26, NextWeekday),
+        once("Early May bank holiday (VE Day)", 1995, May, 8),
+        once("Early May bank holiday (VE Day)", 2020, May, 8),
+        once("Spring bank holiday", 2002, June, 4),
+        once("Spring bank holiday", 2012, June, 4),
+        once("Spring bank holiday", 2022, June, 2),
+        once(
+            "Wedding of Prince Charles and Lady Diana Spencer",
+            1981,
+            July,
+            29,
+        ),
+        once("Millennium Celebrations", 1999, December, 31),
+        once("Golden Jubilee of Elizabeth II", 2002, June, 3),
+        once(
+            "Wedding of Prince William and Catherine Middleton",
+            2011,
+            April,
+            29,
+        ),
+        once("Diamond Jubilee of Elizabeth II", 2012, June, 5),
+        once("Platinum Jubilee of Elizabeth II", 2022, June, 3),
+        once("State Funeral of Queen Elizabeth II", 2022, September, 19),
+        once("Coronation of King Charles III", 2023, May, 8),
+    ];
+
+    let early_may = nth("Early May bank holiday", May, Monday, 1);
+    rules.extend(except(early_may, &[1995, 2020]));
+    let spring = nth("Spring bank holiday", May, Monday, -1);
+    rules.extend(except(spring, &[2002, 2012, 2022]));
+    rules
+}
+
+/// Returns the bank holidays of England and Wales.
+pub(super) fn england_wales() -> Vec<HolidayRule> {
+    let mut rules = vec![
+        fixed("New Year's Day", January, 1, NextWeekday),
+        easter("Easter Monday", 1),
+        nth("Summer bank holiday", August, Monday, -1),
+    ];
+
+    rules.extend(common());
+    rules
+}
+
+/// Returns the bank holidays of Scotland.
+pub(super) fn scotland() -> Vec<HolidayRule> {
+    let mut rules = vec![
+        fixed("New Year's Day", January, 1, NextWeekday),
+        fixed("2nd January", January, 2, NextWeekday),
+        nth("Summer bank holiday", August, Monday, 1),
+        fixed("St Andrew's Day", November, 30, NextWeekday).with_start_year(2007),
+    ];
+
+    rules.extend(common());
+    rules
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+
+    use super::super::tests::assert_year;
+    use crate::HolidayDataset;
+
+    #[test]
+    fn england_wales() {
+        let dataset = HolidayDataset::EnglandWales;
+        let dates = [
+            (January, 3),
+            (April, 15),
+            (April, 18),
+            (May, 2),
+            (June, 2),
+            (June, 3),
+            (August, 29),
+            (September, 19),
+            (December, 26),
+            (December, 27),
+        ];
+        assert_year(dataset, 2022, &dates);
+
+        let dates = [
+            (January, 1),
+            (April, 10),
+            (April, 13),
+            (May, 8),
+            (May, 25),
+            (August, 31),
+            (December, 25),
+            (December, 28),
+        ];
+        assert_year(dataset, 2020, &dates);
+    }
+
+    #[test]
+    fn scotland() {
+        let dataset = HolidayDataset::Scotland;
+        let dates = [
+            (January, 2),
+            (January, 3),
+            (April, 7),
+            (May, 1),
+            (May, 8),
+            (May, 29),
+            (August, 7),
+            (November, 30),
+            (December, 25),
+            (December, 26),
+        ];
+        assert_year(dataset, 2023, &dates);
+
+        let dates = [
+            (January, 3),
+            (January, 4),
+            (April, 15),
+            (May, 2),
+            (June, 2),
+            (June, 3),
+            (August, 1),
+            (September, 19),
+            (November, 30),
+            (December, 26),
+            (December, 27),
+        ];
+        assert_year(dataset, 2022, &dates);
+    }
+}
@@ -0,0 +1,72 @@
+use time::Month::*;
+use time::Weekday::*;
+
+use super::rule::{fixed, nth};
+use crate::{HolidayRule, Observance::*};
+
+/// Returns the federal holidays of 5 U.S.C. 6103.
+pub(super) fn federal() -> Vec<HolidayRule> {
+    vec![
+        fixed("New Year's Day", January, 1, NearestWeekday),
+        nth("Birthday of Martin Luther King, Jr.", January, Monday, 3).with_start_year(1986),
+        nth("Washington's Birthday", February, Monday, 3),
+        nth("Memorial Day", May, Monday, -1),
+        fixed(
+            "Juneteenth National Independence Day",
+            June,
+            19,
+            NearestWeekday,
+        )
+        .with_start_year(2021),
+        fixed("Independence Day", July, 4, NearestWeekday),
+        nth("Labor Day", September, Monday, 1),
+        nth("Columbus Day", October, Monday, 2),
+        nth("Veterans Day", October, Monday, 4).with_end_year(1977),
+        fixed("Veterans Day", November, 11, NearestWeekday).with_start_year(1978),
+        nth("Thanksgiving Day", November, Thursday, 4),
+        fixed("Christmas Day", December, 25, NearestWeekday),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+
+    use super::super::tests::assert_year;
+    use crate::HolidayDataset;
+
+    #[test]
+    fn federal() {
+        let dataset = HolidayDataset::UsFederal;
+        let dates = [
+            (January, 1),
+            (January, 15),
+            (February, 19),
+            (May, 27),
+            (June, 19),
+            (July, 4),
+            (September, 2),
+            (October, 14),
+            (November, 11),
+            (November, 28),
+            (December, 25),
+        ];
+        assert_year(dataset, 2024, &dates);
+
+        let dates = [
+            (January, 1),
+            (January, 18),
+            (February, 15),
+            (May, 31),
+            (June, 18),
+            (July, 5),
+            (September, 6),
+            (October, 11),
+            (November, 11),
+            (November, 25),
+            (December, 24),
+            (December, 31),
+        ];
+        assert_year(dataset, 2021, &dates);
+    }
+}
@@ -0,0 +1,98 @@
+use time::{Date, Duration, Month};
+
+/// The computus used to determine the date of Easter Sunday.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Easter {
+    /// The Gregorian computus of the Western churches.
+    #[default]
+    Western,
+    /// The Julian computus of the Eastern Orthodox churches, expressed as a
+    /// date of the proleptic Gregorian calendar.
+    Orthodox,
+}
+
+impl Easter {
+    /// Returns the date of Easter Sunday in the provided year.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::Easter;
+    /// let d0 = Date::from_calendar_date(2024, March, 31).unwrap();
+    /// let d1 = Date::from_calendar_date(2024, May, 5).unwrap();
+    /// assert_eq!(Easter::Western.sunday(2024), Some(d0));
+    /// assert_eq!(Easter::Orthodox.sunday(2024), Some(d1));
+    /// ```
+    #[must_use]
+    pub fn sunday(self, year: i32) -> Option<Date> {
+        match self {
+            Self::Western => {
+                // The anonymous Gregorian algorithm.
+                let a = year.rem_euclid(19);
+                let (b, c) = (year.div_euclid(100), year.rem_euclid(100));
+                let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+                let f = (b + 8).div_euclid(25);
+                let g = (b - f + 1).div_euclid(3);
+                let h = (19 * a + b - d - g + 15).rem_euclid(30);
+                let (i, k) = (c / 4, c % 4);
+                let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+                let m = (a + 11 * h + 22 * l) / 451;
+                let n = h + l - 7 * m + 114;
+                let month = Month::try_from((n / 31) as u8).ok()?;
+                Date::from_calendar_date(year, month, (n % 31 + 1) as u8).ok()
+            }
+            Self::Orthodox => {
+                // The Meeus Julian algorithm, shifted by the calendar difference.
+                let a = year.rem_euclid(4);
+                let b = year.rem_euclid(7);
+                let c = year.rem_euclid(19);
+                let d = (19 * c + 15) % 30;
+                let e = (2 * a + 4 * b - d + 34).rem_euclid(7);
+                let n = d + e + 114;
+                let month = Month::try_from((n / 31) as u8).ok()?;
+                let date = Date::from_calendar_date(year, month, (n % 31 + 1) as u8).ok()?;
+                let shift = year.div_euclid(100) - year.div_euclid(400) - 2;
+                date.checked_add(Duration::days(shift as i64))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Date;
+    use time::Month::*;
+
+    use crate::Easter;
+
+    #[test]
+    fn sundays() {
+        let western = [
+            (1961, April, 2),
+            (2000, April, 23),
+            (2008, March, 23),
+            (2011, April, 24),
+            (2019, April, 21),
+            (2038, April, 25),
+            (2285, March, 22),
+        ];
+
+        for (year, month, day) in western {
+            let date = Date::from_calendar_date(year, month, day).unwrap();
+            assert_eq!(Easter::Western.sunday(year), Some(date));
+        }
+
+        let orthodox = [
+            (2000, April, 30),
+            (2008, April, 27),
+            (2011, April, 24),
+            (2019, April, 28),
+            (2023, April, 16),
+            (2025, April, 20),
+        ];
+
+        for (year, month, day) in orthodox {
+            let date = Date::from_calendar_date(year, month, day).unwrap();
+            assert_eq!(Easter::Orthodox.sunday(year), Some(date));
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use time::{Date, Duration, Month, Weekday};
+
+use crate::util::days_in_year_month;
+
+#[cfg(any(
+    feature = "holidays-de",
+    feature = "holidays-jp",
+    feature = "holidays-nyse",
+    feature = "holidays-target2",
+    feature = "holidays-uk",
+    feature = "holidays-us",
+))]
+mod dataset;
+#[cfg(any(
+    feature = "holidays-de",
+    feature = "holidays-jp",
+    feature = "holidays-nyse",
+    feature = "holidays-target2",
+    feature = "holidays-uk",
+    feature = "holidays-us",
+))]
+pub use dataset::*;
+
+mod easter;
+pub use easter::*;
+
+mod set;
+pub use set::*;
+
+/// The rule that determines the date of a holiday in a given year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HolidayDate {
+    /// A fixed day of a fixed month, e.g. July 4.
+    Fixed { month: Month, day: u8 },
+    /// The nth weekday of the month, counted from the end if negative,
+    /// e.g. the fourth Thursday of November or the last Monday of May.
+    NthWeekday {
+        month: Month,
+        weekday: Weekday,
+        n: i8,
+    },
+    /// The first weekday on or after a fixed day of a fixed month, e.g. the
+    /// Monday on or after May 18.
+    OnOrAfter {
+        month: Month,
+        day: u8,
+        weekday: Weekday,
+    },
+    /// A number of days relative to Easter Sunday, e.g. -2 for Good Friday.
+    Easter { easter: Easter, offset: i16 },
+}
+
+impl HolidayDate {
+    /// Returns the date in the provided year, or `None` if there is no such
+    /// date, e.g. February 29 of a common year or the fifth Monday of a month.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*, Weekday::*};
+    /// # use timext::HolidayDate;
+    /// let thanksgiving = HolidayDate::NthWeekday { month: November, weekday: Thursday, n: 4 };
+    /// let d0 = Date::from_calendar_date(2024, November, 28).unwrap();
+    /// assert_eq!(thanksgiving.date(2024), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn date(self, year: i32) -> Option<Date> {
+        match self {
+            Self::Fixed { month, day } => Date::from_calendar_date(year, month, day).ok(),
+            Self::NthWeekday { month, weekday, n } => {
+                let length = days_in_year_month(year, month);
+                let date = match n {
+                    1..=5 => {
+                        let first = Date::from_calendar_date(year, month, 1).ok()?;
+                        let shift = weekday.number_days_from_monday() as i64
+                            - first.weekday().number_days_from_monday() as i64;
+                        let day = 1 + shift.rem_euclid(7) + (n as i64 - 1) * 7;
+                        first.replace_day(u8::try_from(day).ok()?).ok()?
+                    }
+                    -5..=-1 => {
+                        let last = Date::from_calendar_date(year, month, length).ok()?;
+                        let shift = last.weekday().number_days_from_monday() as i64
+                            - weekday.number_days_from_monday() as i64;
+                        let day = length as i64 - shift.rem_euclid(7) + (n as i64 + 1) * 7;
+                        last.replace_day(u8::try_from(day).ok()?).ok()?
+                    }
+                    _ => return None,
+                };
+
+                Some(date)
+            }
+            Self::OnOrAfter {
+                month,
+                day,
+                weekday,
+            } => {
+                let date = Date::from_calendar_date(year, month, day).ok()?;
+                let shift = weekday.number_days_from_monday() as i64
+                    - date.weekday().number_days_from_monday() as i64;
+                date.checked_add(Duration::days(shift.rem_euclid(7)))
+            }
+            Self::Easter { easter, offset } => {
+                let sunday = easter.sunday(year)?;
+                sunday.checked_add(Duration::days(offset as i64))
+            }
+        }
+    }
+}
+
+/// The rule that moves a holiday falling on a weekend to the day it is observed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Observance {
+    /// The holiday is observed on the day it falls.
+    #[default]
+    Actual,
+    /// A holiday on Saturday is observed on Friday, and on Sunday on Monday.
+    NearestWeekday,
+    /// A holiday on Saturday or Sunday is observed on the next weekday that
+    /// is not already a holiday, e.g. UK substitute days.
+    NextWeekday,
+    /// A holiday on Sunday is observed on the next day that is not already a
+    /// holiday, e.g. Japanese substitute holidays.
+    SundayToNextDay,
+}
+
+/// A named holiday, recurring every year within an optional range of years.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::{HolidayDate, HolidayRule, Observance};
+/// let date = HolidayDate::Fixed { month: July, day: 4 };
+/// let rule = HolidayRule::new("Independence Day", date)
+///     .with_observance(Observance::NearestWeekday)
+///     .with_start_year(1870);
+///
+/// let d0 = Date::from_calendar_date(2026, July, 3).unwrap();
+/// assert_eq!(rule.observed_date(2026), Some(d0));
+/// assert_eq!(rule.observed_date(1869), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HolidayRule {
+    name: String,
+    date: HolidayDate,
+    observance: Observance,
+    start_year: Option<i32>,
+    end_year: Option<i32>,
+}
+
+impl HolidayRule {
+    /// Creates a new `HolidayRule` observed on the day it falls in every year.
+    #[must_use]
+    pub fn new(name: impl Into<String>, date: HolidayDate) -> Self {
+        Self {
+            name: name.into(),
+            date,
+            observance: Observance::Actual,
+            start_year: None,
+            end_year: None,
+        }
+    }
+
+    /// Sets the rule used to observe the holiday when it falls on a weekend.
+    #[must_use]
+    pub fn with_observance(mut self, observance: Observance) -> Self {
+        self.observance = observance;
+        self
+    }
+
+    /// Sets the first year, inclusive, in which the holiday is observed.
+    #[must_use]
+    pub fn with_start_year(mut self, year: i32) -> Self {
+        self.start_year = Some(year);
+        self
+    }
+
+    /// Sets the last year, inclusive, in which the holiday is observed.
+    #[must_use]
+    pub fn with_end_year(mut self, year: i32) -> Self {
+        self.end_year = Some(year);
+        self
+    }
+
+    /// Returns the name of the holiday.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the rule that determines the date of the holiday.
+    #[must_use]
+    pub const fn date(&self) -> HolidayDate {
+        self.date
+    }
+
+    /// Returns the rule used to observe the holiday.
+    #[must_use]
+    pub const fn observance(&self) -> Observance {
+        self.observance
+    }
+
+    /// Returns the first year in which the holiday is observed, if any.
+    #[must_use]
+    pub const fn start_year(&self) -> Option<i32> {
+        self.start_year
+    }
+
+    /// Returns the last year in which the holiday is observed, if any.
+    #[must_use]
+    pub const fn end_year(&self) -> Option<i32> {
+        self.end_year
+    }
+
+    /// Returns `true` if the holiday is observed in the provided year.
+    #[must_use]
+    pub fn is_valid_in(&self, year: i32) -> bool {
+        self.start_year.is_none_or(|x| x <= year) && self.end_year.is_none_or(|x| x >= year)
+    }
+
+    /// Returns the date the holiday falls on in the provided year, if any.
+    #[must_use]
+    pub fn actual_date(&self, year: i32) -> Option<Date> {
+        match self.is_valid_in(year) {
+            true => self.date.date(year),
+            false => None,
+        }
+    }
+
+    /// Returns the date the holiday is observed on in the provided year, if any.
+    ///
+    /// Other holidays are not taken into account, see [`HolidaySet`] for
+    /// substitute days that skip them.
+    #[must_use]
+    pub fn observed_date(&self, year: i32) -> Option<Date> {
+        let date = self.actual_date(year)?;
+        self.observe(date, |_| false)
+    }
+
+    /// Moves the date to the day it is observed, skipping the taken dates.
+    fn observe(&self, date: Date, taken: impl Fn(Date) -> bool) -> Option<Date> {
+        let weekend = |x: Date| matches!(x.weekday(), Weekday::Saturday | Weekday::Sunday);
+        match (self.observance, date.weekday()) {
+            (Observance::NearestWeekday, Weekday::Saturday) => date.previous_day(),
+            (Observance::NearestWeekday, Weekday::Sunday) => date.next_day(),
+            (Observance::NextWeekday, Weekday::Saturday | Weekday::Sunday) => {
+                let mut date = date.next_day()?;
+                while weekend(date) || taken(date) {
+                    date = date.next_day()?;
+                }
+
+                Some(date)
+            }
+            (Observance::SundayToNextDay, Weekday::Sunday) => {
+                let mut date = date.next_day()?;
+                while taken(date) {
+                    date = date.next_day()?;
+                }
+
+                Some(date)
+            }
+            _ => Some(date),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::Weekday::*;
+    use time::{Date, Weekday};
+
+    use crate::{Easter, HolidayDate};
+
+    fn nth(month: time::Month, weekday: Weekday, n: i8) -> HolidayDate {
+        HolidayDate::NthWeekday { month, weekday, n }
+    }
+
+    #[test]
+    fn dates() {
+        let date = |m, d| Date::from_calendar_date(2024, m, d).unwrap();
+        assert_eq!(nth(January, Monday, 3).date(2024), Some(date(January, 15)));
+        assert_eq!(nth(May, Monday, -1).date(2024), Some(date(May, 27)));
+        assert_eq!(
+            nth(September, Monday, 1).date(2024),
+            Some(date(September, 2))
+        );
+        assert_eq!(nth(March, Sunday, -1).date(2024), Some(date(March, 31)));
+        assert_eq!(nth(March, Friday, 5).date(2024), Some(date(March, 29)));
+        assert_eq!(nth(April, Friday, 5).date(2024), None);
+        assert_eq!(nth(April, Friday, 0).date(2024), None);
+
+        let victoria = HolidayDate::OnOrAfter {
+            month: May,
+            day: 18,
+            weekday: Monday,
+        };
+        assert_eq!(victoria.date(2024), Some(date(May, 20)));
+
+        let whit_monday = HolidayDate::Easter {
+            easter: Easter::Western,
+            offset: 50,
+        };
+        assert_eq!(whit_monday.date(2024), Some(date(May, 20)));
+
+        let leap = HolidayDate::Fixed {
+            month: February,
+            day: 29,
+        };
+        assert_eq!(leap.date(2023), None);
+    }
+}
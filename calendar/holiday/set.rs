@@ -0,0 +1,184 @@
+use std::ops::RangeInclusive;
+
+use time::Date;
+
+use crate::HolidayRule;
+
+/// A set of [`HolidayRule`]s, generating the observed holidays of a range of years.
+///
+/// Substitute days of [`Observance::NextWeekday`] and
+/// [`Observance::SundayToNextDay`] skip the other holidays of the set, in
+/// the order the rules were added.
+///
+/// [`Observance::NextWeekday`]: crate::Observance::NextWeekday
+/// [`Observance::SundayToNextDay`]: crate::Observance::SundayToNextDay
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::{BusinessCalendar, HolidayDate, HolidayRule, HolidaySet, Observance};
+/// let christmas = HolidayDate::Fixed { month: December, day: 25 };
+/// let boxing_day = HolidayDate::Fixed { month: December, day: 26 };
+/// let set = HolidaySet::new()
+///     .with_rule(HolidayRule::new("Christmas Day", christmas).with_observance(Observance::NextWeekday))
+///     .with_rule(HolidayRule::new("Boxing Day", boxing_day).with_observance(Observance::NextWeekday));
+///
+/// let d0 = Date::from_calendar_date(2021, December, 27).unwrap();
+/// let d1 = Date::from_calendar_date(2021, December, 28).unwrap();
+/// assert_eq!(set.dates(2021..=2021), vec![d0, d1]);
+///
+/// let calendar = BusinessCalendar::default().with_holidays(set.dates(2000..=2050));
+/// assert!(!calendar.is_business_day(d1));
+/// ```
+///
+/// With the `serde` feature, a set is declared as a list of `rules`. The
+/// `type` of each rule is one of `fixed`, `nth_weekday`, `on_or_after` and
+/// `easter`, and unknown or missing fields are rejected, except for the
+/// `easter` of an `easter` rule, which defaults to `western`:
+///
+/// ```toml
+/// [[rules]]
+/// name = "Thanksgiving Day"
+/// type = "nth_weekday"
+/// month = 11
+/// weekday = "thursday"
+/// n = 4
+///
+/// [[rules]]
+/// name = "Juneteenth National Independence Day"
+/// type = "fixed"
+/// month = "June"
+/// day = 19
+/// observance = "nearest_weekday"
+/// start_year = 2021
+///
+/// [[rules]]
+/// name = "Good Friday"
+/// type = "easter"
+/// easter = "western"
+/// offset = -2
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct HolidaySet {
+    rules: Vec<HolidayRule>,
+}
+
+impl HolidaySet {
+    /// Creates a new empty `HolidaySet`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Adds a rule.
+    #[must_use]
+    pub fn with_rule(mut self, rule: HolidayRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Adds several rules.
+    #[must_use]
+    pub fn with_rules(mut self, rules: impl IntoIterator<Item = HolidayRule>) -> Self {
+        self.rules.extend(rules);
+        self
+    }
+
+    /// Returns the rules of the set.
+    #[must_use]
+    pub fn rules(&self) -> &[HolidayRule] {
+        &self.rules
+    }
+
+    /// Returns the observed holidays of the years with their rules, in
+    /// ascending order of the date.
+    ///
+    /// Observed dates may fall outside of the years, e.g. a New Year's Day
+    /// on Saturday observed on the last Friday of the previous year.
+    #[must_use]
+    pub fn holidays(&self, years: RangeInclusive<i32>) -> Vec<(Date, &HolidayRule)> {
+        let mut holidays = Vec::new();
+        for year in years {
+            let actual: Vec<_> = self
+                .rules
+                .iter()
+                .filter_map(|x| Some((x.actual_date(year)?, x)))
+                .collect();
+
+            let mut taken: Vec<_> = actual.iter().map(|x| x.0).collect();
+            taken.sort_unstable();
+            for (date, rule) in actual {
+                let Some(observed) = rule.observe(date, |x| taken.binary_search(&x).is_ok()) else {
+                    continue;
+                };
+
+                if let Err(index) = taken.binary_search(&observed) {
+                    taken.insert(index, observed);
+                }
+
+                holidays.push((observed, rule));
+            }
+        }
+
+        holidays.sort_by_key(|x| x.0);
+        holidays
+    }
+
+    /// Returns the observed holidays of the years in ascending order, without
+    /// duplicates.
+    #[must_use]
+    pub fn dates(&self, years: RangeInclusive<i32>) -> Vec<Date> {
+        let mut dates: Vec<_> = self.holidays(years).into_iter().map(|x| x.0).collect();
+        dates.dedup();
+        dates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::{Date, Month};
+
+    use crate::{HolidayDate, HolidayRule, HolidaySet, Observance};
+
+    fn fixed(name: &str, month: Month, day: u8, observance: Observance) -> HolidayRule {
+        HolidayRule::new(name, HolidayDate::Fixed { month, day }).with_observance(observance)
+    }
+
+    #[test]
+    fn substitutes() {
+        // Japanese Golden Week of 2020, with Constitution Day on Sunday.
+        let set = HolidaySet::new()
+            .with_rule(fixed("Constitution", May, 3, Observance::SundayToNextDay))
+            .with_rule(fixed("Greenery", May, 4, Observance::SundayToNextDay))
+            .with_rule(fixed("Children", May, 5, Observance::SundayToNextDay));
+
+        let days: Vec<_> = set.dates(2020..=2020).iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![4, 5, 6]);
+    }
+
+    #[test]
+    fn validity() {
+        let d0 = Date::from_calendar_date(2022, January, 1).unwrap();
+        let set = HolidaySet::new()
+            .with_rule(fixed("New Year", January, 1, Observance::NearestWeekday))
+            .with_rule(
+                fixed("Juneteenth", June, 19, Observance::NearestWeekday).with_start_year(2021),
+            )
+            .with_rule(fixed("Old", March, 1, Observance::Actual).with_end_year(2020));
+
+        let holidays = set.holidays(2020..=2022);
+        let names: Vec<_> = holidays.iter().map(|x| x.1.name()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "New Year",
+                "Old",
+                "New Year",
+                "Juneteenth",
+                "New Year",
+                "Juneteenth"
+            ]
+        );
+        assert_eq!(holidays[4].0, d0.previous_day().unwrap());
+    }
+}
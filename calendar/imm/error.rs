@@ -0,0 +1,52 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The kind of [`ParseImmCodeError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseImmCodeErrorKind {
+    /// A month letter that is missing or not one of `FGHJKMNQUVXZ`.
+    InvalidMonth,
+    /// A year that is missing, malformed or out of range.
+    InvalidYear,
+}
+
+/// An error returned when parsing an [`ImmCode`] fails.
+///
+/// [`ImmCode`]: crate::ImmCode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseImmCodeError {
+    kind: ParseImmCodeErrorKind,
+    offset: usize,
+}
+
+impl ParseImmCodeError {
+    pub(crate) const fn new(kind: ParseImmCodeErrorKind, offset: usize) -> Self {
+        Self { kind, offset }
+    }
+
+    /// Returns the kind of the error.
+    #[must_use]
+    pub const fn kind(&self) -> ParseImmCodeErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset of the input at which the error occurred.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for ParseImmCodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let message = match self.kind {
+            ParseImmCodeErrorKind::InvalidMonth => "invalid month code",
+            ParseImmCodeErrorKind::InvalidYear => "invalid year",
+        };
+
+        write!(f, "{} at byte {}", message, self.offset)
+    }
+}
+
+impl Error for ParseImmCodeError {}
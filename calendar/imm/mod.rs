@@ -0,0 +1,358 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use time::{Date, Month, Weekday};
+
+use crate::util::days_in_year_month;
+use crate::{BusinessCalendar, CalendarDuration, HolidayDate};
+
+mod error;
+pub use error::*;
+
+/// The month codes of futures contracts, from January to December.
+const MONTH_CODES: [char; 12] = ['F', 'G', 'H', 'J', 'K', 'M', 'N', 'Q', 'U', 'V', 'X', 'Z'];
+
+/// Returns the nth weekday of the month.
+fn nth_weekday(year: i32, month: Month, weekday: Weekday, n: i8) -> Option<Date> {
+    HolidayDate::NthWeekday { month, weekday, n }.date(year)
+}
+
+/// The contract month of a futures or options contract, written as a month
+/// letter and a year, e.g. `H4` or `M25`.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::ImmCode;
+/// let d0 = Date::from_calendar_date(2024, March, 20).unwrap();
+/// let d1 = Date::from_calendar_date(2025, June, 18).unwrap();
+/// let code = ImmCode::from_date(d0);
+/// assert_eq!(code.to_string(), "H4");
+/// assert_eq!(format!("{:#}", code), "H24");
+/// assert_eq!(code.date(), Some(d0));
+///
+/// let code = ImmCode::parse("M25", d0).unwrap();
+/// assert_eq!(code.date(), Some(d1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImmCode {
+    year: i32,
+    month: Month,
+}
+
+impl ImmCode {
+    /// Creates a new `ImmCode` for the month of the year.
+    #[must_use]
+    pub const fn new(year: i32, month: Month) -> Self {
+        Self { year, month }
+    }
+
+    /// Creates a new `ImmCode` for the month of the date.
+    #[must_use]
+    pub const fn from_date(date: Date) -> Self {
+        Self::new(date.year(), date.month())
+    }
+
+    /// Parses a month letter followed by a year of one, two or four digits.
+    ///
+    /// A year of one or two digits resolves to the first contract with the
+    /// same last digits whose IMM date is on or after the reference date.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::ImmCode;
+    /// let d0 = Date::from_calendar_date(2024, March, 21).unwrap();
+    /// assert_eq!(ImmCode::parse("H4", d0).unwrap(), ImmCode::new(2034, March));
+    /// assert_eq!(ImmCode::parse("M4", d0).unwrap(), ImmCode::new(2024, June));
+    /// assert_eq!(ImmCode::parse("Z2019", d0).unwrap(), ImmCode::new(2019, December));
+    /// assert!(ImmCode::parse("A4", d0).is_err());
+    /// ```
+    pub fn parse(code: &str, reference: Date) -> Result<Self, ParseImmCodeError> {
+        use ParseImmCodeErrorKind::*;
+
+        let mut chars = code.chars();
+        let letter = chars.next().map(|x| x.to_ascii_uppercase());
+        let month = MONTH_CODES.iter().position(|x| Some(*x) == letter);
+        let month = month.and_then(|x| Month::try_from(x as u8 + 1).ok());
+        let month = month.ok_or(ParseImmCodeError::new(InvalidMonth, 0))?;
+
+        let digits = chars.as_str();
+        let error = ParseImmCodeError::new(InvalidYear, 1);
+        if !digits.bytes().all(|x| x.is_ascii_digit()) {
+            return Err(error);
+        }
+
+        let value: i32 = digits.parse().map_err(|_| error)?;
+        let modulus = match digits.len() {
+            1 => 10,
+            2 => 100,
+            4 => return Ok(Self::new(value, month)),
+            _ => return Err(error),
+        };
+
+        let year = reference.year();
+        let year = year + (value - year).rem_euclid(modulus);
+        let code = Self::new(year, month);
+        match code.date() {
+            Some(date) if date >= reference => Ok(code),
+            _ => Ok(Self::new(year.checked_add(modulus).ok_or(error)?, month)),
+        }
+    }
+
+    /// Returns the year of the contract.
+    #[must_use]
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the contract.
+    #[must_use]
+    pub const fn month(&self) -> Month {
+        self.month
+    }
+
+    /// Returns the letter of the month of the contract.
+    #[must_use]
+    pub const fn letter(&self) -> char {
+        MONTH_CODES[self.month as usize - 1]
+    }
+
+    /// Returns the IMM date of the contract month, its third Wednesday.
+    #[must_use]
+    pub fn date(&self) -> Option<Date> {
+        nth_weekday(self.year, self.month, Weekday::Wednesday, 3)
+    }
+}
+
+impl Display for ImmCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match f.alternate() {
+            true => write!(f, "{}{:02}", self.letter(), self.year.rem_euclid(100)),
+            false => write!(f, "{}{}", self.letter(), self.year.rem_euclid(10)),
+        }
+    }
+}
+
+/// The months whose third Wednesday is an IMM date.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImmCycle {
+    /// March, June, September and December.
+    #[default]
+    Quarterly,
+    /// Every month, e.g. for serial contracts.
+    Monthly,
+}
+
+impl ImmCycle {
+    /// Returns `true` if the month is part of the cycle.
+    const fn contains_month(self, month: Month) -> bool {
+        match self {
+            Self::Quarterly => (month as u8).is_multiple_of(3),
+            Self::Monthly => true,
+        }
+    }
+
+    /// Returns `true` if the date is an IMM date of the cycle.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::ImmCycle;
+    /// let d0 = Date::from_calendar_date(2024, September, 18).unwrap();
+    /// let d1 = Date::from_calendar_date(2024, October, 16).unwrap();
+    /// assert!(ImmCycle::Quarterly.contains(d0));
+    /// assert!(!ImmCycle::Quarterly.contains(d1));
+    /// assert!(ImmCycle::Monthly.contains(d1));
+    /// ```
+    #[must_use]
+    pub fn contains(self, date: Date) -> bool {
+        self.contains_month(date.month()) && ImmCode::from_date(date).date() == Some(date)
+    }
+
+    /// Returns the IMM date of the month `months` after the month of the
+    /// date, if the month is part of the cycle.
+    fn imm_date(self, date: Date, months: i32) -> Option<Date> {
+        let first = date.replace_day(1).ok()?;
+        let month = CalendarDuration::checked_date_add(first, CalendarDuration::months(months))?;
+        match self.contains_month(month.month()) {
+            true => ImmCode::from_date(month).date(),
+            false => None,
+        }
+    }
+
+    /// Returns the first IMM date of the cycle after the date.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::ImmCycle;
+    /// let d0 = Date::from_calendar_date(2024, December, 18).unwrap();
+    /// let d1 = Date::from_calendar_date(2025, March, 19).unwrap();
+    /// assert_eq!(ImmCycle::Quarterly.next(d0), Some(d1));
+    /// assert_eq!(ImmCycle::Quarterly.previous(d1), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn next(self, date: Date) -> Option<Date> {
+        (0..=12)
+            .filter_map(|x| self.imm_date(date, x))
+            .find(|x| *x > date)
+    }
+
+    /// Returns the last IMM date of the cycle before the date.
+    #[must_use]
+    pub fn previous(self, date: Date) -> Option<Date> {
+        (0..=12)
+            .filter_map(|x| self.imm_date(date, -x))
+            .find(|x| *x < date)
+    }
+}
+
+/// The rule that determines the expiry date of a contract in its contract month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExpiryRule {
+    /// The third Wednesday, or the previous business day, e.g. interest rate
+    /// futures.
+    ThirdWednesday,
+    /// The third Friday, or the previous business day, e.g. equity index
+    /// futures and options.
+    ThirdFriday,
+    /// The business day a number of business days before the last business
+    /// day of the month, e.g. 0 for the last business day itself.
+    LastBusinessDay { days_before: u8 },
+}
+
+impl ExpiryRule {
+    /// Returns the expiry date of the contract under the business calendar.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{BusinessCalendar, ExpiryRule, ImmCode};
+    /// let good_friday = Date::from_calendar_date(2025, April, 18).unwrap();
+    /// let calendar = BusinessCalendar::default().with_holiday(good_friday);
+    ///
+    /// let rule = ExpiryRule::ThirdFriday;
+    /// let d0 = Date::from_calendar_date(2025, April, 17).unwrap();
+    /// assert_eq!(rule.expiry(ImmCode::new(2025, April), &calendar), Some(d0));
+    ///
+    /// let rule = ExpiryRule::LastBusinessDay { days_before: 3 };
+    /// let d1 = Date::from_calendar_date(2025, May, 27).unwrap();
+    /// assert_eq!(rule.expiry(ImmCode::new(2025, May), &calendar), Some(d1));
+    /// ```
+    #[must_use]
+    pub fn expiry(self, code: ImmCode, calendar: &BusinessCalendar) -> Option<Date> {
+        let (year, month) = (code.year(), code.month());
+        match self {
+            Self::ThirdWednesday => {
+                calendar.roll_backward(nth_weekday(year, month, Weekday::Wednesday, 3)?)
+            }
+            Self::ThirdFriday => {
+                calendar.roll_backward(nth_weekday(year, month, Weekday::Friday, 3)?)
+            }
+            Self::LastBusinessDay { days_before } => {
+                let last = Date::from_calendar_date(year, month, days_in_year_month(year, month));
+                let days = -(days_before as i64);
+                calendar.checked_add_business_days(calendar.roll_backward(last.ok()?)?, days)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::{Date, Month};
+
+    use crate::{
+        BusinessCalendar, ExpiryRule, ImmCode, ImmCycle, ParseImmCodeErrorKind, WeekendMask,
+    };
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn cycle() {
+        let dates = [
+            date(2024, March, 20),
+            date(2024, June, 19),
+            date(2024, September, 18),
+            date(2024, December, 18),
+            date(2025, March, 19),
+        ];
+
+        let cycle = ImmCycle::Quarterly;
+        for pair in dates.windows(2) {
+            assert!(cycle.contains(pair[0]));
+            assert_eq!(cycle.next(pair[0]), Some(pair[1]));
+            assert_eq!(cycle.next(pair[1].previous_day().unwrap()), Some(pair[1]));
+            assert_eq!(cycle.previous(pair[1]), Some(pair[0]));
+            assert_eq!(cycle.previous(pair[0].next_day().unwrap()), Some(pair[0]));
+        }
+
+        let cycle = ImmCycle::Monthly;
+        let d0 = date(2024, January, 17);
+        assert_eq!(cycle.next(d0), Some(date(2024, February, 21)));
+        assert_eq!(cycle.previous(d0), Some(date(2023, December, 20)));
+        assert!(!cycle.contains(date(2024, January, 10)));
+    }
+
+    #[test]
+    fn codes() {
+        let reference = date(2024, January, 1);
+        let mut code = ImmCode::from_date(reference);
+        for _ in 0..240 {
+            let imm = code.date().unwrap();
+            assert_eq!(ImmCode::from_date(imm), code);
+            assert_eq!(ImmCode::parse(&format!("{:#}", code), reference), Ok(code));
+            if imm < date(2034, January, 1) {
+                assert_eq!(ImmCode::parse(&code.to_string(), reference), Ok(code));
+            }
+
+            let next = ImmCycle::Monthly.next(imm).unwrap();
+            code = ImmCode::from_date(next);
+        }
+
+        let reference = date(2024, June, 20);
+        assert_eq!(
+            ImmCode::parse("m4", reference),
+            Ok(ImmCode::new(2034, June))
+        );
+        assert_eq!(
+            ImmCode::parse("M24", reference),
+            Ok(ImmCode::new(2124, June))
+        );
+        assert_eq!(
+            ImmCode::parse("U4", reference),
+            Ok(ImmCode::new(2024, September))
+        );
+
+        let error = |x| ImmCode::parse(x, reference).unwrap_err().kind();
+        assert_eq!(error(""), ParseImmCodeErrorKind::InvalidMonth);
+        assert_eq!(error("I4"), ParseImmCodeErrorKind::InvalidMonth);
+        assert_eq!(error("H"), ParseImmCodeErrorKind::InvalidYear);
+        assert_eq!(error("H123"), ParseImmCodeErrorKind::InvalidYear);
+        assert_eq!(error("H+4"), ParseImmCodeErrorKind::InvalidYear);
+    }
+
+    #[test]
+    fn expiry() {
+        let calendar = BusinessCalendar::new(WeekendMask::SATURDAY_SUNDAY)
+            .with_holiday(date(2024, December, 25))
+            .with_holiday(date(2024, December, 31));
+        let code = ImmCode::new(2024, December);
+        assert_eq!(
+            ExpiryRule::ThirdWednesday.expiry(code, &calendar),
+            Some(date(2024, December, 18))
+        );
+        assert_eq!(
+            ExpiryRule::ThirdFriday.expiry(code, &calendar),
+            Some(date(2024, December, 20))
+        );
+
+        let rule = |days_before| ExpiryRule::LastBusinessDay { days_before };
+        assert_eq!(
+            rule(0).expiry(code, &calendar),
+            Some(date(2024, December, 30))
+        );
+        assert_eq!(
+            rule(2).expiry(code, &calendar),
+            Some(date(2024, December, 26))
+        );
+    }
+}
@@ -0,0 +1,20 @@
+mod business;
+pub use business::*;
+
+mod holiday;
+pub use holiday::*;
+
+mod day_count;
+pub use day_count::*;
+
+mod schedule;
+pub use schedule::*;
+
+mod imm;
+pub use imm::*;
+
+mod fiscal;
+pub use fiscal::*;
+
+mod retail;
+pub use retail::*;
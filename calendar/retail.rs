@@ -0,0 +1,471 @@
+use time::{Date, Duration, Month, Weekday};
+
+use crate::util::days_in_year_month;
+use crate::FiscalYearNaming;
+
+/// The number of weeks in each of the three periods of a retail quarter.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RetailPattern {
+    /// Periods of 4, 4 and 5 weeks.
+    #[default]
+    Weeks445,
+    /// Periods of 4, 5 and 4 weeks.
+    Weeks454,
+    /// Periods of 5, 4 and 4 weeks.
+    Weeks544,
+}
+
+impl RetailPattern {
+    /// Returns the number of weeks in each period of a quarter.
+    #[must_use]
+    pub const fn weeks(self) -> [u8; 3] {
+        match self {
+            Self::Weeks445 => [4, 4, 5],
+            Self::Weeks454 => [4, 5, 4],
+            Self::Weeks544 => [5, 4, 4],
+        }
+    }
+}
+
+/// The rule that selects the last day of a retail year.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RetailYearEnd {
+    /// The year ends on the last weekday of the month.
+    #[default]
+    LastWeekday,
+    /// The year ends on the weekday nearest to the last day of the month,
+    /// which may fall in the first days of the following month.
+    NearestWeekday,
+}
+
+/// The period that receives the extra week of a 53-week year.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeapWeekPlacement {
+    /// The last period of the year is extended, as in the NRF calendar.
+    #[default]
+    LastPeriod,
+    /// The first period of the year is extended.
+    FirstPeriod,
+}
+
+/// A date in a [`RetailCalendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RetailDate {
+    year: i32,
+    week: u8,
+    day: u8,
+    period: u8,
+}
+
+impl RetailDate {
+    /// Returns the retail year.
+    #[must_use]
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the quarter of the year, in `1..=4`.
+    #[must_use]
+    pub const fn quarter(&self) -> u8 {
+        (self.period - 1) / 3 + 1
+    }
+
+    /// Returns the period of the year, in `1..=12`.
+    #[must_use]
+    pub const fn period(&self) -> u8 {
+        self.period
+    }
+
+    /// Returns the week of the year, in `1..=53`.
+    #[must_use]
+    pub const fn week(&self) -> u8 {
+        self.week
+    }
+
+    /// Returns the day of the week, in `1..=7`, counted from the first day of the retail week.
+    #[must_use]
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+/// A 52/53-week calendar with years made of four quarters of 13 weeks.
+///
+/// Every year ends on a fixed weekday at the end of a fixed month, so years
+/// have 52 or 53 whole weeks. The extra week of a 53-week year extends one of
+/// the periods, see [`LeapWeekPlacement`].
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::RetailCalendar;
+/// let calendar = RetailCalendar::NRF;
+/// let d0 = Date::from_calendar_date(2024, January, 15).unwrap();
+/// let retail = calendar.retail_date(d0).unwrap();
+/// assert_eq!(retail.year(), 2023);
+/// assert_eq!((retail.quarter(), retail.period(), retail.week()), (4, 12, 51));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RetailCalendar {
+    pattern: RetailPattern,
+    month: Month,
+    weekday: Weekday,
+    year_end: RetailYearEnd,
+    leap_week: LeapWeekPlacement,
+    naming: FiscalYearNaming,
+}
+
+impl RetailCalendar {
+    /// The 4-5-4 calendar of the National Retail Federation.
+    ///
+    /// Years end on the Saturday nearest to the end of January, and are named
+    /// by the calendar year they start in.
+    pub const NRF: Self = Self::new(
+        RetailPattern::Weeks454,
+        Month::January,
+        Weekday::Saturday,
+        RetailYearEnd::NearestWeekday,
+    );
+
+    /// Creates a new `RetailCalendar` whose years end on the weekday selected
+    /// by the rule at the end of the month.
+    ///
+    /// The extra week is placed in the last period and years are named by
+    /// the calendar year they start in.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*, Weekday::*};
+    /// # use timext::{RetailCalendar, RetailPattern, RetailYearEnd};
+    /// let pattern = RetailPattern::Weeks445;
+    /// let calendar = RetailCalendar::new(pattern, August, Saturday, RetailYearEnd::LastWeekday);
+    /// let d0 = Date::from_calendar_date(2024, August, 31).unwrap();
+    /// assert_eq!(calendar.year_end(2023), Some(d0));
+    /// ```
+    #[must_use]
+    pub const fn new(
+        pattern: RetailPattern,
+        month: Month,
+        weekday: Weekday,
+        year_end: RetailYearEnd,
+    ) -> Self {
+        Self {
+            pattern,
+            month,
+            weekday,
+            year_end,
+            leap_week: LeapWeekPlacement::LastPeriod,
+            naming: FiscalYearNaming::StartYear,
+        }
+    }
+
+    /// Sets the period that receives the extra week of a 53-week year.
+    #[must_use]
+    pub const fn with_leap_week(mut self, placement: LeapWeekPlacement) -> Self {
+        self.leap_week = placement;
+        self
+    }
+
+    /// Sets the naming convention of the retail year.
+    ///
+    /// Years are named as if they started on the first day of the month that
+    /// follows the year-end month.
+    #[must_use]
+    pub const fn with_naming(mut self, naming: FiscalYearNaming) -> Self {
+        self.naming = naming;
+        self
+    }
+}
+
+impl RetailCalendar {
+    /// Returns the calendar year of the year-end month of the named year.
+    fn end_year(&self, year: i32) -> i32 {
+        match self.naming {
+            FiscalYearNaming::StartYear if self.month != Month::December => year + 1,
+            _ => year,
+        }
+    }
+
+    /// Returns the name of the year whose year-end month is in the calendar year.
+    fn name(&self, end_year: i32) -> i32 {
+        match self.naming {
+            FiscalYearNaming::StartYear if self.month != Month::December => end_year - 1,
+            _ => end_year,
+        }
+    }
+
+    /// Returns the last day of the year whose year-end month is in the calendar year.
+    fn end_in(&self, end_year: i32) -> Option<Date> {
+        let day = days_in_year_month(end_year, self.month);
+        let last = Date::from_calendar_date(end_year, self.month, day).ok()?;
+        let ahead = (self.weekday.number_days_from_monday() as i64
+            - last.weekday().number_days_from_monday() as i64)
+            .rem_euclid(7);
+
+        match self.year_end {
+            RetailYearEnd::NearestWeekday if ahead <= 3 => last.checked_add(Duration::days(ahead)),
+            _ => last.checked_sub(Duration::days((7 - ahead) % 7)),
+        }
+    }
+
+    /// Returns the number of weeks in each period of a year of the provided length.
+    fn period_weeks(&self, weeks: u8) -> [u8; 12] {
+        let pattern = self.pattern.weeks();
+        let mut periods = [0; 12];
+        for (i, period) in periods.iter_mut().enumerate() {
+            *period = pattern[i % 3];
+        }
+
+        if weeks == 53 {
+            match self.leap_week {
+                LeapWeekPlacement::LastPeriod => periods[11] += 1,
+                LeapWeekPlacement::FirstPeriod => periods[0] += 1,
+            }
+        }
+
+        periods
+    }
+
+    /// Returns the first day of the retail year.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::RetailCalendar;
+    /// let d0 = Date::from_calendar_date(2023, January, 29).unwrap();
+    /// assert_eq!(RetailCalendar::NRF.year_start(2023), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn year_start(&self, year: i32) -> Option<Date> {
+        self.end_in(self.end_year(year) - 1)?.next_day()
+    }
+
+    /// Returns the last day of the retail year.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::RetailCalendar;
+    /// let d0 = Date::from_calendar_date(2024, February, 3).unwrap();
+    /// assert_eq!(RetailCalendar::NRF.year_end(2023), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn year_end(&self, year: i32) -> Option<Date> {
+        self.end_in(self.end_year(year))
+    }
+
+    /// Returns the number of weeks in the retail year, either 52 or 53.
+    ///
+    /// ```rust
+    /// # use timext::RetailCalendar;
+    /// assert_eq!(RetailCalendar::NRF.weeks_in_year(2022), Some(52));
+    /// assert_eq!(RetailCalendar::NRF.weeks_in_year(2023), Some(53));
+    /// ```
+    #[must_use]
+    pub fn weeks_in_year(&self, year: i32) -> Option<u8> {
+        let start = self.year_start(year)?;
+        let end = self.year_end(year)?;
+        Some(((end - start).whole_days() / 7 + 1) as u8)
+    }
+
+    /// Returns the first day of the period, in `1..=12`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::RetailCalendar;
+    /// let d0 = Date::from_calendar_date(2024, March, 3).unwrap();
+    /// assert_eq!(RetailCalendar::NRF.period_start(2024, 2), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn period_start(&self, year: i32, period: u8) -> Option<Date> {
+        if !(1..=12).contains(&period) {
+            return None;
+        }
+
+        let periods = self.period_weeks(self.weeks_in_year(year)?);
+        let weeks: u8 = periods[..period as usize - 1].iter().sum();
+        self.week_start(year, weeks + 1)
+    }
+
+    /// Returns the last day of the period, in `1..=12`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::RetailCalendar;
+    /// let d0 = Date::from_calendar_date(2024, April, 6).unwrap();
+    /// assert_eq!(RetailCalendar::NRF.period_end(2024, 2), Some(d0));
+    /// ```
+    #[must_use]
+    pub fn period_end(&self, year: i32, period: u8) -> Option<Date> {
+        match period {
+            12 => self.year_end(year),
+            _ => self.period_start(year, period + 1)?.previous_day(),
+        }
+    }
+
+    /// Returns the first day of the quarter, in `1..=4`.
+    #[must_use]
+    pub fn quarter_start(&self, year: i32, quarter: u8) -> Option<Date> {
+        match quarter {
+            1..=4 => self.period_start(year, quarter * 3 - 2),
+            _ => None,
+        }
+    }
+
+    /// Returns the last day of the quarter, in `1..=4`.
+    #[must_use]
+    pub fn quarter_end(&self, year: i32, quarter: u8) -> Option<Date> {
+        match quarter {
+            1..=4 => self.period_end(year, quarter * 3),
+            _ => None,
+        }
+    }
+
+    /// Returns the first day of the week, in `1..=53`.
+    #[must_use]
+    pub fn week_start(&self, year: i32, week: u8) -> Option<Date> {
+        self.date(year, week, 1)
+    }
+
+    /// Returns the date of the day, in `1..=7`, of the week of the retail year.
+    ///
+    /// Returns `None` if the week does not exist in the year.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::RetailCalendar;
+    /// let d0 = Date::from_calendar_date(2024, February, 3).unwrap();
+    /// assert_eq!(RetailCalendar::NRF.date(2023, 53, 7), Some(d0));
+    /// assert_eq!(RetailCalendar::NRF.date(2022, 53, 7), None);
+    /// ```
+    #[must_use]
+    pub fn date(&self, year: i32, week: u8, day: u8) -> Option<Date> {
+        if week == 0 || week > self.weeks_in_year(year)? || !(1..=7).contains(&day) {
+            return None;
+        }
+
+        let days = (week as i64 - 1) * 7 + day as i64 - 1;
+        self.year_start(year)?.checked_add(Duration::days(days))
+    }
+
+    /// Returns the retail year, period, week and day that contain the date.
+    ///
+    /// Returns `None` if the retail year of the date is not representable.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::RetailCalendar;
+    /// let d0 = Date::from_calendar_date(2024, February, 3).unwrap();
+    /// let retail = RetailCalendar::NRF.retail_date(d0).unwrap();
+    /// assert_eq!((retail.year(), retail.period(), retail.week()), (2023, 12, 53));
+    /// ```
+    #[must_use]
+    pub fn retail_date(&self, date: Date) -> Option<RetailDate> {
+        let mut end_year = date.year();
+        if date > self.end_in(end_year)? {
+            end_year += 1;
+        } else if date <= self.end_in(end_year - 1)? {
+            end_year -= 1;
+        }
+
+        let year = self.name(end_year);
+        let days = (date - self.year_start(year)?).whole_days();
+        let week = (days / 7 + 1) as u8;
+        let day = (days % 7 + 1) as u8;
+
+        let mut period = 1;
+        let mut weeks = 0;
+        for length in self.period_weeks(self.weeks_in_year(year)?) {
+            weeks += length;
+            if week <= weeks {
+                break;
+            }
+
+            period += 1;
+        }
+
+        Some(RetailDate {
+            year,
+            week,
+            day,
+            period,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::Weekday::*;
+    use time::{Date, Duration};
+
+    use crate::{
+        FiscalYearNaming, LeapWeekPlacement, RetailCalendar, RetailPattern, RetailYearEnd,
+    };
+
+    #[test]
+    fn nrf_years() {
+        let calendar = RetailCalendar::NRF;
+        let years = [
+            (2017, (2017, January, 29), (2018, February, 3)),
+            (2018, (2018, February, 4), (2019, February, 2)),
+            (2022, (2022, January, 30), (2023, January, 28)),
+            (2023, (2023, January, 29), (2024, February, 3)),
+            (2024, (2024, February, 4), (2025, February, 1)),
+        ];
+
+        for (year, (y0, m0, d0), (y1, m1, d1)) in years {
+            let d0 = Date::from_calendar_date(y0, m0, d0).unwrap();
+            let d1 = Date::from_calendar_date(y1, m1, d1).unwrap();
+            assert_eq!(calendar.year_start(year), Some(d0));
+            assert_eq!(calendar.year_end(year), Some(d1));
+        }
+
+        for year in [2000, 2006, 2012, 2017, 2023] {
+            assert_eq!(calendar.weeks_in_year(year), Some(53));
+        }
+    }
+
+    #[test]
+    fn nrf_periods() {
+        let calendar = RetailCalendar::NRF;
+        let d0 = Date::from_calendar_date(2024, April, 7).unwrap();
+        let d1 = Date::from_calendar_date(2024, May, 4).unwrap();
+        assert_eq!(calendar.period_start(2024, 3), Some(d0));
+        assert_eq!(calendar.period_end(2024, 3), Some(d1));
+
+        let d2 = Date::from_calendar_date(2023, December, 31).unwrap();
+        let d3 = Date::from_calendar_date(2024, February, 3).unwrap();
+        assert_eq!(calendar.period_start(2023, 12), Some(d2));
+        assert_eq!(calendar.quarter_end(2023, 4), Some(d3));
+        assert_eq!(d3 - d2, Duration::weeks(5) - Duration::DAY);
+    }
+
+    #[test]
+    fn round_trip() {
+        let pattern = RetailPattern::Weeks544;
+        let calendar =
+            RetailCalendar::new(pattern, December, Sunday, RetailYearEnd::NearestWeekday)
+                .with_leap_week(LeapWeekPlacement::FirstPeriod)
+                .with_naming(FiscalYearNaming::EndYear);
+
+        let mut date = Date::from_calendar_date(2019, January, 1).unwrap();
+        let end = Date::from_calendar_date(2029, January, 1).unwrap();
+        while date < end {
+            let retail = calendar.retail_date(date).unwrap();
+            let period = retail.period();
+            let year = retail.year();
+            assert_eq!(calendar.date(year, retail.week(), retail.day()), Some(date));
+            assert!(calendar.period_start(year, period).unwrap() <= date);
+            assert!(calendar.period_end(year, period).unwrap() >= date);
+            date = date.next_day().unwrap();
+        }
+    }
+
+    #[test]
+    fn leap_week_placement() {
+        let pattern = RetailPattern::Weeks445;
+        let calendar =
+            RetailCalendar::new(pattern, January, Saturday, RetailYearEnd::NearestWeekday)
+                .with_leap_week(LeapWeekPlacement::FirstPeriod);
+        let d0 = calendar.period_start(2023, 1).unwrap();
+        let d1 = calendar.period_start(2023, 2).unwrap();
+        assert_eq!(d1 - d0, Duration::weeks(5));
+    }
+}
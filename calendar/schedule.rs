@@ -0,0 +1,433 @@
+use time::Date;
+
+use crate::util::days_in_year_month;
+use crate::{BusinessCalendar, BusinessDayConvention, CalendarDuration, ImmCode};
+
+/// The direction in which the regular dates of a [`Schedule`] are generated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScheduleDirection {
+    /// Steps forward from the effective date, leaving any stub at the back.
+    Forward,
+    /// Steps backward from the termination date, leaving any stub at the front.
+    #[default]
+    Backward,
+}
+
+/// The length of the stub of a [`Schedule`], when the tenor does not divide it evenly.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StubConvention {
+    /// The stub is a period of its own, shorter than a regular one.
+    #[default]
+    Short,
+    /// The stub is merged with the neighbouring regular period.
+    Long,
+}
+
+/// The day of the month the regular dates of a [`Schedule`] fall on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RollConvention {
+    /// The last day of the month.
+    EndOfMonth,
+    /// The third Wednesday of the month.
+    Imm,
+    /// A fixed day of the month, or the last day of a shorter month.
+    Day(u8),
+}
+
+impl RollConvention {
+    /// Moves the date to the roll day of its month.
+    fn roll(self, date: Date) -> Option<Date> {
+        let (year, month) = (date.year(), date.month());
+        match self {
+            Self::EndOfMonth => date.replace_day(days_in_year_month(year, month)).ok(),
+            Self::Imm => ImmCode::new(year, month).date(),
+            Self::Day(day) => date
+                .replace_day(day.min(days_in_year_month(year, month)))
+                .ok(),
+        }
+    }
+}
+
+/// A period of a [`Schedule`], with both its unadjusted and adjusted dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SchedulePeriod {
+    unadjusted_start: Date,
+    unadjusted_end: Date,
+    start: Date,
+    end: Date,
+    stub: bool,
+}
+
+impl SchedulePeriod {
+    /// Returns the start date of the period, before the business day adjustment.
+    #[must_use]
+    pub const fn unadjusted_start(&self) -> Date {
+        self.unadjusted_start
+    }
+
+    /// Returns the end date of the period, before the business day adjustment.
+    #[must_use]
+    pub const fn unadjusted_end(&self) -> Date {
+        self.unadjusted_end
+    }
+
+    /// Returns the start date of the period.
+    #[must_use]
+    pub const fn start(&self) -> Date {
+        self.start
+    }
+
+    /// Returns the end date of the period.
+    #[must_use]
+    pub const fn end(&self) -> Date {
+        self.end
+    }
+
+    /// Returns `true` if the period is a stub, shorter or longer than a regular one.
+    #[must_use]
+    pub const fn is_stub(&self) -> bool {
+        self.stub
+    }
+}
+
+/// A schedule of periods stepping by a tenor from an effective date to a
+/// termination date, e.g. the coupon periods of a bond or a swap.
+///
+/// Regular dates are generated from the effective or termination date by
+/// multiples of the tenor, so days of the month clamped in a shorter month
+/// do not drift.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::{BusinessCalendar, RollConvention, Schedule};
+/// # use timext::ext::NumericCalendarDuration;
+/// let d0 = Date::from_calendar_date(2024, January, 15).unwrap();
+/// let d1 = Date::from_calendar_date(2025, March, 31).unwrap();
+/// let schedule = Schedule::new(d0, d1, 6.months()).with_roll(RollConvention::EndOfMonth);
+///
+/// let calendar = BusinessCalendar::default();
+/// let periods = schedule.periods(&calendar).unwrap();
+/// let ends = periods.iter().map(|x| x.unadjusted_end());
+/// let ends: Vec<_> = ends.map(|x| (x.month(), x.day())).collect();
+/// assert_eq!(ends, vec![(March, 31), (September, 30), (March, 31)]);
+/// assert!(periods[0].is_stub());
+///
+/// // Sunday, March 31 is adjusted to Friday, March 29.
+/// assert_eq!(periods[0].end().day(), 29);
+/// # assert_eq!(periods[2].end(), d1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Schedule {
+    effective: Date,
+    termination: Date,
+    tenor: CalendarDuration,
+    direction: ScheduleDirection,
+    stub: StubConvention,
+    roll: Option<RollConvention>,
+    convention: BusinessDayConvention,
+}
+
+impl Schedule {
+    /// Creates a new `Schedule` generated backward with a short stub, the
+    /// day of the month of the termination date and the
+    /// [`BusinessDayConvention::ModifiedFollowing`] adjustment.
+    #[must_use]
+    pub const fn new(effective: Date, termination: Date, tenor: CalendarDuration) -> Self {
+        Self {
+            effective,
+            termination,
+            tenor,
+            direction: ScheduleDirection::Backward,
+            stub: StubConvention::Short,
+            roll: None,
+            convention: BusinessDayConvention::ModifiedFollowing,
+        }
+    }
+
+    /// Sets the direction in which the regular dates are generated.
+    #[must_use]
+    pub const fn with_direction(mut self, direction: ScheduleDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the length of the stub.
+    #[must_use]
+    pub const fn with_stub(mut self, stub: StubConvention) -> Self {
+        self.stub = stub;
+        self
+    }
+
+    /// Sets the day of the month the regular dates fall on.
+    #[must_use]
+    pub const fn with_roll(mut self, roll: RollConvention) -> Self {
+        self.roll = Some(roll);
+        self
+    }
+
+    /// Sets the rule used to adjust the dates to business days.
+    #[must_use]
+    pub const fn with_convention(mut self, convention: BusinessDayConvention) -> Self {
+        self.convention = convention;
+        self
+    }
+
+    /// Returns the first date of the schedule.
+    #[must_use]
+    pub const fn effective(&self) -> Date {
+        self.effective
+    }
+
+    /// Returns the last date of the schedule.
+    #[must_use]
+    pub const fn termination(&self) -> Date {
+        self.termination
+    }
+
+    /// Returns the length of a regular period.
+    #[must_use]
+    pub const fn tenor(&self) -> CalendarDuration {
+        self.tenor
+    }
+
+    /// Returns the direction in which the regular dates are generated.
+    #[must_use]
+    pub const fn direction(&self) -> ScheduleDirection {
+        self.direction
+    }
+
+    /// Returns the length of the stub.
+    #[must_use]
+    pub const fn stub(&self) -> StubConvention {
+        self.stub
+    }
+
+    /// Returns the day of the month the regular dates fall on, if any.
+    #[must_use]
+    pub const fn roll(&self) -> Option<RollConvention> {
+        self.roll
+    }
+
+    /// Returns the rule used to adjust the dates to business days.
+    #[must_use]
+    pub const fn convention(&self) -> BusinessDayConvention {
+        self.convention
+    }
+}
+
+impl Schedule {
+    /// Returns the unadjusted dates of the schedule, from the effective date
+    /// to the termination date, and whether there is a stub.
+    ///
+    /// Returns `None` if the effective date is not before the termination
+    /// date, if the tenor is not positive, or if a date is out of range.
+    fn unadjusted_dates(&self) -> Option<(Vec<Date>, bool)> {
+        let (effective, termination) = (self.effective, self.termination);
+        if effective >= termination || !self.tenor.is_positive() || self.tenor.whole_days() < 0 {
+            return None;
+        }
+
+        let (anchor, sign) = match self.direction {
+            ScheduleDirection::Forward => (effective, 1),
+            ScheduleDirection::Backward => (termination, -1),
+        };
+
+        let mut dates = vec![anchor];
+        let mut stub = false;
+        for periods in 1.. {
+            let duration = self.tenor.checked_mul(sign * periods)?;
+            let mut date = CalendarDuration::checked_date_add(anchor, duration)?;
+            if let Some(roll) = self.roll {
+                date = roll.roll(date)?;
+            }
+
+            let last = *dates.last().unwrap();
+            let (inside, after_last) = match self.direction {
+                ScheduleDirection::Forward => (date < termination, date > last),
+                ScheduleDirection::Backward => (date > effective, date < last),
+            };
+
+            if !inside {
+                stub = date != effective && date != termination;
+                break;
+            }
+
+            if after_last {
+                dates.push(date);
+            }
+        }
+
+        match self.direction {
+            ScheduleDirection::Forward => dates.push(termination),
+            ScheduleDirection::Backward => {
+                dates.push(effective);
+                dates.reverse();
+            }
+        }
+
+        if stub && self.stub == StubConvention::Long && dates.len() > 2 {
+            match self.direction {
+                ScheduleDirection::Forward => dates.remove(dates.len() - 2),
+                ScheduleDirection::Backward => dates.remove(1),
+            };
+        }
+
+        Some((dates, stub))
+    }
+
+    /// Returns the periods of the schedule, adjusted to business days of the
+    /// calendar.
+    ///
+    /// Returns `None` if the effective date is not before the termination
+    /// date, if the tenor is not positive, or if a date is out of range.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{BusinessCalendar, Schedule, ScheduleDirection, StubConvention};
+    /// # use timext::ext::NumericCalendarDuration;
+    /// let d0 = Date::from_calendar_date(2024, January, 10).unwrap();
+    /// let d1 = Date::from_calendar_date(2024, December, 10).unwrap();
+    /// let schedule = Schedule::new(d0, d1, 3.months())
+    ///     .with_direction(ScheduleDirection::Forward)
+    ///     .with_stub(StubConvention::Long);
+    ///
+    /// let periods = schedule.periods(&BusinessCalendar::default()).unwrap();
+    /// assert_eq!(periods.len(), 3);
+    /// assert_eq!(periods[2].unadjusted_start().month(), July);
+    /// assert!(periods[2].is_stub());
+    /// ```
+    #[must_use]
+    pub fn periods(&self, calendar: &BusinessCalendar) -> Option<Vec<SchedulePeriod>> {
+        let (dates, stub) = self.unadjusted_dates()?;
+        let adjusted = dates
+            .iter()
+            .map(|x| self.convention.adjust(*x, calendar))
+            .collect::<Option<Vec<_>>>()?;
+
+        let last = dates.len() - 2;
+        let periods = (0..=last).map(|i| SchedulePeriod {
+            unadjusted_start: dates[i],
+            unadjusted_end: dates[i + 1],
+            start: adjusted[i],
+            end: adjusted[i + 1],
+            stub: stub
+                && match self.direction {
+                    ScheduleDirection::Forward => i == last,
+                    ScheduleDirection::Backward => i == 0,
+                },
+        });
+
+        Some(periods.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::{Date, Month};
+
+    use crate::ext::NumericCalendarDuration;
+    use crate::{
+        BusinessCalendar, BusinessDayConvention, RollConvention, Schedule, ScheduleDirection,
+        StubConvention, WeekendMask,
+    };
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    fn unadjusted(schedule: Schedule) -> Vec<(i32, Month, u8)> {
+        let calendar = BusinessCalendar::new(WeekendMask::NONE);
+        let periods = schedule.periods(&calendar).unwrap();
+        let mut dates = vec![periods[0].unadjusted_start()];
+        dates.extend(periods.iter().map(|x| x.unadjusted_end()));
+        dates.iter().map(|x| x.to_calendar_date()).collect()
+    }
+
+    #[test]
+    fn stubs() {
+        let schedule = Schedule::new(date(2024, February, 1), date(2025, January, 15), 3.months());
+        let dates = unadjusted(schedule);
+        let expected = [
+            (2024, February, 1),
+            (2024, April, 15),
+            (2024, July, 15),
+            (2024, October, 15),
+            (2025, January, 15),
+        ];
+        assert_eq!(dates, expected);
+
+        let dates = unadjusted(schedule.with_stub(StubConvention::Long));
+        assert_eq!(dates, [expected[0], expected[2], expected[3], expected[4]]);
+
+        let schedule = schedule.with_direction(ScheduleDirection::Forward);
+        let dates = unadjusted(schedule);
+        let expected = [
+            (2024, February, 1),
+            (2024, May, 1),
+            (2024, August, 1),
+            (2024, November, 1),
+            (2025, January, 15),
+        ];
+        assert_eq!(dates, expected);
+
+        let dates = unadjusted(schedule.with_stub(StubConvention::Long));
+        assert_eq!(dates, [expected[0], expected[1], expected[2], expected[4]]);
+
+        let schedule = Schedule::new(date(2024, January, 15), date(2025, January, 15), 6.months());
+        let calendar = BusinessCalendar::new(WeekendMask::NONE);
+        let periods = schedule.periods(&calendar).unwrap();
+        assert_eq!(periods.len(), 2);
+        assert!(periods.iter().all(|x| !x.is_stub()));
+
+        let schedule = Schedule::new(date(2024, January, 15), date(2024, January, 15), 6.months());
+        assert_eq!(schedule.periods(&calendar), None);
+        let schedule = Schedule::new(date(2024, January, 15), date(2025, January, 15), 0.months());
+        assert_eq!(schedule.periods(&calendar), None);
+    }
+
+    #[test]
+    fn rolls() {
+        let schedule = Schedule::new(date(2024, January, 31), date(2024, June, 30), 1.months())
+            .with_direction(ScheduleDirection::Forward);
+        let days: Vec<_> = unadjusted(schedule).iter().map(|x| x.2).collect();
+        assert_eq!(days, [31, 29, 31, 30, 31, 30]);
+
+        let schedule = schedule.with_roll(RollConvention::EndOfMonth);
+        let schedule = schedule.with_direction(ScheduleDirection::Backward);
+        let days: Vec<_> = unadjusted(schedule).iter().map(|x| x.2).collect();
+        assert_eq!(days, [31, 29, 31, 30, 31, 30]);
+
+        let schedule = Schedule::new(date(2024, March, 20), date(2025, March, 19), 3.months())
+            .with_direction(ScheduleDirection::Forward)
+            .with_roll(RollConvention::Imm);
+        let dates = unadjusted(schedule);
+        let expected = [
+            (2024, March, 20),
+            (2024, June, 19),
+            (2024, September, 18),
+            (2024, December, 18),
+            (2025, March, 19),
+        ];
+        assert_eq!(dates, expected);
+
+        let schedule = Schedule::new(date(2024, January, 10), date(2024, April, 20), 1.months())
+            .with_roll(RollConvention::Day(20));
+        let days: Vec<_> = unadjusted(schedule).iter().map(|x| x.2).collect();
+        assert_eq!(days, [10, 20, 20, 20, 20]);
+    }
+
+    #[test]
+    fn adjusted() {
+        let calendar = BusinessCalendar::new(WeekendMask::SATURDAY_SUNDAY)
+            .with_holiday(date(2024, December, 25));
+        let schedule = Schedule::new(date(2024, June, 25), date(2025, June, 25), 6.months())
+            .with_convention(BusinessDayConvention::Following);
+        let periods = schedule.periods(&calendar).unwrap();
+
+        assert_eq!(periods[0].unadjusted_end(), date(2024, December, 25));
+        assert_eq!(periods[0].end(), date(2024, December, 26));
+        assert_eq!(periods[1].start(), date(2024, December, 26));
+        assert_eq!(periods[1].end(), date(2025, June, 25));
+    }
+}
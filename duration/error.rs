@@ -0,0 +1,66 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The kind of [`ParseDurationError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseDurationErrorKind {
+    /// The input ended before a complete duration was read.
+    UnexpectedEnd,
+    /// A character that is not valid at this position.
+    UnexpectedCharacter,
+    /// A designator or unit that is repeated or out of order.
+    MisplacedDesignator,
+    /// A fractional value, which cannot be represented.
+    FractionalValue,
+    /// A non-zero time component, which cannot be represented.
+    TimeComponent,
+    /// A value that does not fit into a `CalendarDuration`.
+    Overflow,
+}
+
+/// An error returned when parsing a [`CalendarDuration`] fails.
+///
+/// [`CalendarDuration`]: crate::CalendarDuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseDurationError {
+    kind: ParseDurationErrorKind,
+    offset: usize,
+}
+
+impl ParseDurationError {
+    pub(crate) const fn new(kind: ParseDurationErrorKind, offset: usize) -> Self {
+        Self { kind, offset }
+    }
+
+    /// Returns the kind of the error.
+    #[must_use]
+    pub const fn kind(&self) -> ParseDurationErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset of the input at which the error occurred.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for ParseDurationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let message = match self.kind {
+            ParseDurationErrorKind::UnexpectedEnd => "unexpected end of input",
+            ParseDurationErrorKind::UnexpectedCharacter => "unexpected character",
+            ParseDurationErrorKind::MisplacedDesignator => {
+                "repeated or out of order designator or unit"
+            }
+            ParseDurationErrorKind::FractionalValue => "fractional values are not supported",
+            ParseDurationErrorKind::TimeComponent => "time components are not supported",
+            ParseDurationErrorKind::Overflow => "value is out of range",
+        };
+
+        write!(f, "{} at byte {}", message, self.offset)
+    }
+}
+
+impl Error for ParseDurationError {}
@@ -1,2 +1,14 @@
 mod month;
 pub use month::*;
+
+mod period;
+pub use period::*;
+
+mod policy;
+pub use policy::*;
+
+mod error;
+pub use error::*;
+
+mod unit;
+pub use unit::*;
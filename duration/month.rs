@@ -1,15 +1,20 @@
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
-use time::util::days_in_year_month;
-use time::{Date, Month};
+use time::{Date, Duration, Month};
 
-/// A span of time with month precision.
+use crate::util::days_in_year_month;
+use crate::{DayPolicy, ParseDurationError, ParseDurationErrorKind as Kind};
+
+/// A span of time with nominal months and days.
+///
+/// Days are applied after the months, so the day of the month is clamped
+/// before any days are added.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CalendarDuration {
-    /// TODO Represent fraction of week with opt f32
-    /// see `<https://github.com/xwde/timext/issues/6>`
     months: i32,
+    days: i32,
 }
 
 impl CalendarDuration {
@@ -23,12 +28,40 @@ impl CalendarDuration {
     /// ```
     #[must_use]
     pub fn new(years: i32, months: i32) -> Self {
+        Self::new_with_days(years, months, 0, 0)
+    }
+
+    /// Creates a new `CalendarDuration` with provided years, months, weeks and days.
+    ///
+    /// ```rust
+    /// # use timext::CalendarDuration;
+    /// let d0 = CalendarDuration::new_with_days(2, 24, 1, 3);
+    /// assert_eq!(d0.whole_years(), 4);
+    /// assert_eq!(d0.whole_months(), 48);
+    /// assert_eq!(d0.whole_weeks(), 1);
+    /// assert_eq!(d0.whole_days(), 10);
+    /// ```
+    #[must_use]
+    pub fn new_with_days(years: i32, months: i32, weeks: i32, days: i32) -> Self {
         let months = years
             .checked_mul(12)
             .expect("overflow constructing `timext::CalendarDuration`")
             .checked_add(months)
             .expect("overflow constructing `timext::CalendarDuration`");
-        Self { months }
+        let days = weeks
+            .checked_mul(7)
+            .expect("overflow constructing `timext::CalendarDuration`")
+            .checked_add(days)
+            .expect("overflow constructing `timext::CalendarDuration`");
+        Self { months, days }
+    }
+
+    /// Returns the `CalendarDuration` with the days added.
+    pub(crate) const fn with_added_days(self, days: i32) -> Self {
+        match self.days.checked_add(days) {
+            Some(days) => Self { days, ..self },
+            None => panic!("overflow constructing `timext::CalendarDuration`"),
+        }
     }
 
     /// Creates a new `CalendarDuration` with provided years.
@@ -57,8 +90,40 @@ impl CalendarDuration {
         Self::new(0, months)
     }
 
-    pub const MIN: Self = Self { months: i32::MIN };
-    pub const MAX: Self = Self { months: i32::MAX };
+    /// Creates a new `CalendarDuration` with provided weeks.
+    ///
+    /// ```rust
+    /// # use timext::CalendarDuration;
+    /// let d0 = CalendarDuration::weeks(2);
+    /// assert_eq!(d0.whole_weeks(), 2);
+    /// assert_eq!(d0.whole_days(), 14);
+    /// ```
+    #[must_use]
+    pub fn weeks(weeks: i32) -> Self {
+        Self::new_with_days(0, 0, weeks, 0)
+    }
+
+    /// Creates a new `CalendarDuration` with provided days.
+    ///
+    /// ```rust
+    /// # use timext::CalendarDuration;
+    /// let d0 = CalendarDuration::days(10);
+    /// assert_eq!(d0.whole_weeks(), 1);
+    /// assert_eq!(d0.whole_days(), 10);
+    /// ```
+    #[must_use]
+    pub fn days(days: i32) -> Self {
+        Self::new_with_days(0, 0, 0, days)
+    }
+
+    pub const MIN: Self = Self {
+        months: i32::MIN,
+        days: i32::MIN,
+    };
+    pub const MAX: Self = Self {
+        months: i32::MAX,
+        days: i32::MAX,
+    };
 }
 
 impl CalendarDuration {
@@ -102,42 +167,90 @@ impl CalendarDuration {
         self.months % 12
     }
 
-    /// Checks if a duration is negative.
+    /// Returns the number of whole weeks in the `CalendarDuration`.
+    ///
+    /// ```rust
+    /// # use timext::ext::NumericCalendarDuration;
+    /// assert_eq!(1.weeks().whole_weeks(), 1);
+    /// assert_eq!((-1).weeks().whole_weeks(), -1);
+    /// assert_eq!(6.days().whole_weeks(), 0);
+    /// assert_eq!(1.months().whole_weeks(), 0);
+    /// ```
+    #[must_use]
+    pub const fn whole_weeks(self) -> i32 {
+        self.days / 7
+    }
+
+    /// Returns the number of whole days in the `CalendarDuration`.
+    ///
+    /// ```rust
+    /// # use timext::ext::NumericCalendarDuration;
+    /// assert_eq!(1.weeks().whole_days(), 7);
+    /// assert_eq!((-1).days().whole_days(), -1);
+    /// assert_eq!(1.months().whole_days(), 0);
+    /// ```
+    #[must_use]
+    pub const fn whole_days(self) -> i32 {
+        self.days
+    }
+
+    /// Returns the number of days past the number of whole weeks.
+    ///
+    /// ```rust
+    /// # use timext::ext::NumericCalendarDuration;
+    /// assert_eq!(8.days().subweek_days(), 1);
+    /// assert_eq!((-8).days().subweek_days(), -1);
+    /// ```
+    #[must_use]
+    pub const fn subweek_days(self) -> i32 {
+        self.days % 7
+    }
+
+    /// Checks if a duration is zero.
     ///
     /// ```rust
     /// # use timext::ext::NumericCalendarDuration;
     /// assert!(0.months().is_zero());
     /// assert!(!1.months().is_zero());
+    /// assert!(!1.days().is_zero());
     /// ```
     #[must_use]
     pub const fn is_zero(self) -> bool {
-        self.months == 0
+        self.months == 0 && self.days == 0
     }
 
     /// Checks if a `CalendarDuration` is positive.
     ///
+    /// The sign is taken from the months, or from the days if there are no months.
+    ///
     /// ```rust
     /// # use timext::ext::NumericCalendarDuration;
     /// assert!(1.months().is_positive());
+    /// assert!(1.days().is_positive());
+    /// assert!((1.months() - 1.days()).is_positive());
     /// assert!(!0.months().is_positive());
     /// assert!(!(-1).months().is_positive());
     /// ```
     #[must_use]
     pub const fn is_positive(self) -> bool {
-        self.months.is_positive()
+        self.months.is_positive() || (self.months == 0 && self.days.is_positive())
     }
 
     /// Checks if a `CalendarDuration` is negative.
     ///
+    /// The sign is taken from the months, or from the days if there are no months.
+    ///
     /// ```rust
     /// # use timext::ext::NumericCalendarDuration;
     /// assert!((-1).months().is_negative());
+    /// assert!((-1).days().is_negative());
+    /// assert!((1.days() - 1.months()).is_negative());
     /// assert!(!0.months().is_negative());
     /// assert!(!1.months().is_negative());
     /// ```
     #[must_use]
     pub const fn is_negative(self) -> bool {
-        self.months.is_negative()
+        self.months.is_negative() || (self.months == 0 && self.days.is_negative())
     }
 }
 
@@ -149,52 +262,93 @@ impl CalendarDuration {
     /// assert_eq!(1.months().abs(), 1.months());
     /// assert_eq!(0.months().abs(), 0.months());
     /// assert_eq!((-1).months().abs(), 1.months());
+    /// assert_eq!((1.days() - 1.months()).abs(), 1.months() - 1.days());
     /// ```
     #[must_use]
     pub fn abs(self) -> Self {
-        Self::months(self.whole_months().abs())
+        match self.is_negative() {
+            true => -self,
+            false => self,
+        }
     }
 }
 
 impl CalendarDuration {
     /// Returns the sum of provided `Date` and `CalendarDuration`.
     ///
+    /// Months are added first, clamping the day to the length of the resulting
+    /// month, then the days are added.
+    ///
     /// ```rust
-    /// # use time::{Date, Month::{October, September}};
+    /// # use time::{Date, Month::{August, October, September}};
     /// # use timext::{CalendarDuration, ext::NumericCalendarDuration};
     /// let d0 = Date::from_calendar_date(2018, September, 1).unwrap();
     /// let d1 = Date::from_calendar_date(2018, October, 1).unwrap();
     /// let rs = CalendarDuration::checked_date_add(d0, 1.months());
     /// assert_eq!(rs.unwrap(), d1);
+    ///
+    /// let d0 = Date::from_calendar_date(2018, August, 31).unwrap();
+    /// let d1 = Date::from_calendar_date(2018, October, 1).unwrap();
+    /// let rs = CalendarDuration::checked_date_add(d0, 1.months() + 1.days());
+    /// assert_eq!(rs.unwrap(), d1);
     /// ```
     #[must_use]
     pub fn checked_date_add(date: Date, duration: Self) -> Option<Date> {
-        // [1, 12] + [-11, 11]
-        let month = duration.subyear_months();
-        let month = month.checked_add(date.month() as i32)?;
-
-        // Aug(8) + 6 = Feb(2) or Feb(2) - 6 = Aug(8)
-        let added = match month {
-            x if x.is_positive() => x / 12,
-            _ => -1,
-        };
+        Self::checked_date_add_with(date, duration, DayPolicy::Clamp)
+    }
 
-        debug_assert!((-1..=1).contains(&added));
+    /// Returns the sum of provided `Date` and `CalendarDuration`, resolving a
+    /// day of the month that does not exist in the resulting month with the
+    /// provided [`DayPolicy`].
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::{January, February, March}};
+    /// # use timext::{CalendarDuration, DayPolicy, ext::NumericCalendarDuration};
+    /// let d0 = Date::from_calendar_date(2023, January, 31).unwrap();
+    ///
+    /// let d1 = Date::from_calendar_date(2023, February, 28).unwrap();
+    /// let rs = CalendarDuration::checked_date_add_with(d0, 1.months(), DayPolicy::Clamp);
+    /// assert_eq!(rs, Some(d1));
+    ///
+    /// let d1 = Date::from_calendar_date(2023, March, 3).unwrap();
+    /// let rs = CalendarDuration::checked_date_add_with(d0, 1.months(), DayPolicy::Overflow);
+    /// assert_eq!(rs, Some(d1));
+    ///
+    /// let rs = CalendarDuration::checked_date_add_with(d0, 1.months(), DayPolicy::Reject);
+    /// assert_eq!(rs, None);
+    /// ```
+    #[must_use]
+    pub fn checked_date_add_with(date: Date, duration: Self, policy: DayPolicy) -> Option<Date> {
+        // [0, 11] + [-11, 11]
+        let month = duration.subyear_months() + date.month() as i32 - 1;
+
+        // Aug(7) + 6 = Feb(1) of the next year, Feb(1) - 6 = Aug(7) of the previous year
         let year = duration
             .whole_years()
-            .checked_add(added)?
+            .checked_add(month.div_euclid(12))?
             .checked_add(date.year())?;
 
-        // Feb(2) - 6 = -4; 12 - 4 = Aug(8)
-        let month = match month {
-            x if x.is_positive() => x % 12,
-            x => 12 + x,
+        let month = Month::try_from(month.rem_euclid(12) as u8 + 1).unwrap();
+        let length = days_in_year_month(year, month);
+        let day = length.min(date.day());
+        let clamped = Date::from_calendar_date(year, month, day).ok()?;
+        let date = match policy {
+            DayPolicy::Clamp => clamped,
+            DayPolicy::EndOfMonth
+                if date.day() == days_in_year_month(date.year(), date.month()) =>
+            {
+                clamped.replace_day(length).ok()?
+            }
+            DayPolicy::EndOfMonth => clamped,
+            DayPolicy::Overflow => {
+                let excess = date.day() - day;
+                clamped.checked_add(Duration::days(excess as i64))?
+            }
+            DayPolicy::Reject if day != date.day() => return None,
+            DayPolicy::Reject => clamped,
         };
 
-        debug_assert!((1..=12).contains(&month));
-        let month = Month::try_from(month as u8).unwrap();
-        let day = days_in_year_month(year, month).min(date.day());
-        Date::from_calendar_date(year, month, day).ok()
+        date.checked_add(Duration::days(duration.days as i64))
     }
 
     /// Returns the difference of provided `Date` and `CalendarDuration`.
@@ -212,6 +366,60 @@ impl CalendarDuration {
         let duration = duration.checked_neg()?;
         Self::checked_date_add(date, duration)
     }
+
+    /// Returns the difference of provided `Date` and `CalendarDuration`, resolving
+    /// a day of the month that does not exist in the resulting month with the
+    /// provided [`DayPolicy`].
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::March};
+    /// # use timext::{CalendarDuration, DayPolicy, ext::NumericCalendarDuration};
+    /// let d0 = Date::from_calendar_date(2024, March, 30).unwrap();
+    /// let d1 = Date::from_calendar_date(2024, March, 1).unwrap();
+    /// let rs = CalendarDuration::checked_date_sub_with(d0, 1.months(), DayPolicy::Overflow);
+    /// assert_eq!(rs, Some(d1));
+    /// ```
+    #[must_use]
+    pub fn checked_date_sub_with(date: Date, duration: Self, policy: DayPolicy) -> Option<Date> {
+        let duration = duration.checked_neg()?;
+        Self::checked_date_add_with(date, duration, policy)
+    }
+
+    /// Returns the `CalendarDuration` between provided `Date`s.
+    ///
+    /// The result holds the largest number of whole months that does not step
+    /// past `end`, and the remaining days, so that `start + between(start, end)`
+    /// is exactly `end`. If `end` is before `start`, the result is negative.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::{January, February, March}};
+    /// # use timext::{CalendarDuration, ext::NumericCalendarDuration};
+    /// let d0 = Date::from_calendar_date(2023, January, 31).unwrap();
+    /// let d1 = Date::from_calendar_date(2023, March, 30).unwrap();
+    /// assert_eq!(CalendarDuration::between(d0, d1), 1.months() + 30.days());
+    /// assert_eq!(d0 + CalendarDuration::between(d0, d1), d1);
+    ///
+    /// let d0 = Date::from_calendar_date(2023, March, 31).unwrap();
+    /// let d1 = Date::from_calendar_date(2023, February, 27).unwrap();
+    /// assert_eq!(CalendarDuration::between(d0, d1), (-1).months() - 1.days());
+    /// ```
+    #[must_use]
+    pub fn between(start: Date, end: Date) -> Self {
+        let years = end.year() - start.year();
+        let months = end.month() as i32 - start.month() as i32;
+        let mut months = Self::months(years * 12 + months);
+
+        // The clamped day may step past the end by at most a month.
+        let date = start + months;
+        if end >= start && date > end {
+            months -= Self::months(1);
+        } else if end < start && date < end {
+            months += Self::months(1);
+        }
+
+        let days = (end - (start + months)).whole_days();
+        months + Self::days(days as i32)
+    }
 }
 
 impl CalendarDuration {
@@ -224,7 +432,9 @@ impl CalendarDuration {
     /// assert_eq!((-5).months().checked_add(5.months()), Some(0.months()));
     /// ```
     pub fn checked_add(self, rhs: Self) -> Option<Self> {
-        self.months.checked_add(rhs.months).map(Self::months)
+        let months = self.months.checked_add(rhs.months)?;
+        let days = self.days.checked_add(rhs.days)?;
+        Some(Self { months, days })
     }
 
     /// Computes `self - rhs`, returning `None` if an overflow occurred.
@@ -236,7 +446,9 @@ impl CalendarDuration {
     /// assert_eq!(5.months().checked_sub(5.months()), Some(0.months()));
     /// ```
     pub fn checked_sub(self, rhs: Self) -> Option<Self> {
-        self.months.checked_sub(rhs.months).map(Self::months)
+        let months = self.months.checked_sub(rhs.months)?;
+        let days = self.days.checked_sub(rhs.days)?;
+        Some(Self { months, days })
     }
 
     /// Computes `self * rhs`, returning `None` if an overflow occurred.
@@ -250,7 +462,9 @@ impl CalendarDuration {
     /// assert_eq!(CalendarDuration::MIN.checked_mul(2), None);
     /// ```
     pub fn checked_mul(self, rhs: i32) -> Option<Self> {
-        self.months.checked_mul(rhs).map(Self::months)
+        let months = self.months.checked_mul(rhs)?;
+        let days = self.days.checked_mul(rhs)?;
+        Some(Self { months, days })
     }
 
     /// Computes `self / rhs`, returning `None` if `rhs == 0` or if the result would overflow.
@@ -262,7 +476,9 @@ impl CalendarDuration {
     /// assert_eq!(1.months().checked_div(0), None);
     /// ```
     pub fn checked_div(self, rhs: i32) -> Option<Self> {
-        self.months.checked_div(rhs).map(Self::months)
+        let months = self.months.checked_div(rhs)?;
+        let days = self.days.checked_div(rhs)?;
+        Some(Self { months, days })
     }
 
     /// Computes `-self`, returning `None` if an overflow occurred.
@@ -273,24 +489,160 @@ impl CalendarDuration {
     /// assert_eq!(CalendarDuration::MIN.checked_neg(), None);
     /// ```
     pub fn checked_neg(self) -> Option<Self> {
-        self.months.checked_neg().map(Self::months)
+        let months = self.months.checked_neg()?;
+        let days = self.days.checked_neg()?;
+        Some(Self { months, days })
     }
 }
 
 impl Display for CalendarDuration {
+    /// Formats the `CalendarDuration` as e.g. `1y 2mo 1w 3d`, or with the alternate
+    /// flag as e.g. `1 year 2 months 1 week 3 days`.
+    ///
+    /// Every component carries its own sign, and the output is accepted by `FromStr`.
+    ///
+    /// ```rust
+    /// # use timext::ext::NumericCalendarDuration;
+    /// assert_eq!(14.months().to_string(), "1y 2mo");
+    /// assert_eq!((-14).months().to_string(), "-1y -2mo");
+    /// assert_eq!((1.months() - 10.days()).to_string(), "1mo -1w -3d");
+    /// assert_eq!(format!("{:#}", 14.months() + 1.days()), "1 year 2 months 1 day");
+    /// assert_eq!(format!("{:>8}", 0.months()), "      0d");
+    /// ```
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        if self.is_negative() {
-            f.write_str("-")?;
+        let components = [
+            (self.whole_years(), "y", "year"),
+            (self.subyear_months(), "mo", "month"),
+            (self.whole_weeks(), "w", "week"),
+            (self.subweek_days(), "d", "day"),
+        ];
+
+        let mut output = String::new();
+        for (value, short, long) in components {
+            if value == 0 {
+                continue;
+            }
+
+            if !output.is_empty() {
+                output.push(' ');
+            }
+
+            match (f.alternate(), value.unsigned_abs()) {
+                (false, _) => write!(output, "{}{}", value, short),
+                (true, 1) => write!(output, "{} {}", value, long),
+                (true, _) => write!(output, "{} {}s", value, long),
+            }?;
+        }
+
+        if output.is_empty() {
+            output.push_str(if f.alternate() { "0 days" } else { "0d" });
         }
 
-        let years = self.abs().whole_years();
-        let months = self.abs().subyear_months();
-        match (self.is_zero(), years, months) {
-            (true, _, _) => (0f32).fmt(f).and_then(|_| f.write_str("mo")),
-            (_, y, _) if y.is_positive() => y.fmt(f).and_then(|_| f.write_str("y")),
-            (_, _, m) if m.is_positive() => m.fmt(f).and_then(|_| f.write_str("mo")),
-            (_, _, _) => unreachable!(),
+        f.pad(&output)
+    }
+}
+
+impl FromStr for CalendarDuration {
+    type Err = ParseDurationError;
+
+    /// Parses a `CalendarDuration` in the format produced by `Display`.
+    ///
+    /// Both short (`1y 2mo`) and long (`1 year 2 months`) unit names are accepted.
+    /// With the `parsing` feature ISO 8601 durations are accepted as well, see
+    /// `CalendarDuration::parse_iso8601`.
+    ///
+    /// ```rust
+    /// # use timext::{CalendarDuration, ParseDurationErrorKind};
+    /// # use timext::ext::NumericCalendarDuration;
+    /// assert_eq!("1y 2mo".parse(), Ok(14.months()));
+    /// assert_eq!("1 year 2 months 1 day".parse(), Ok(14.months() + 1.days()));
+    /// assert_eq!("1mo -1w -3d".parse(), Ok(1.months() - 10.days()));
+    ///
+    /// let e0 = "1y 2h".parse::<CalendarDuration>().unwrap_err();
+    /// assert_eq!(e0.kind(), ParseDurationErrorKind::UnexpectedCharacter);
+    /// assert_eq!(e0.offset(), 4);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[cfg(feature = "parsing")]
+        if s.trim_start_matches(['-', '+']).starts_with('P') {
+            return Self::parse_iso8601(s);
+        }
+
+        let bytes = s.as_bytes();
+        let mut pos = 0;
+
+        let mut values = [0i64; 4];
+        let mut next = 0;
+
+        loop {
+            if pos == bytes.len() {
+                match next {
+                    0 => return Err(ParseDurationError::new(Kind::UnexpectedEnd, pos)),
+                    _ => break,
+                }
+            }
+
+            if next > 0 {
+                if bytes[pos] != b' ' {
+                    return Err(ParseDurationError::new(Kind::UnexpectedCharacter, pos));
+                }
+
+                pos += 1;
+            }
+
+            let start = pos;
+            if bytes.get(pos) == Some(&b'-') {
+                pos += 1;
+            }
+
+            let digits = pos;
+            while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                pos += 1;
+            }
+
+            match (pos == digits, bytes.get(pos)) {
+                (true, None) => return Err(ParseDurationError::new(Kind::UnexpectedEnd, pos)),
+                (true, _) => return Err(ParseDurationError::new(Kind::UnexpectedCharacter, pos)),
+                (false, _) => {}
+            }
+
+            let value = s[start..pos]
+                .parse::<i32>()
+                .map_err(|_| ParseDurationError::new(Kind::Overflow, start))?;
+
+            if bytes.get(pos) == Some(&b' ') {
+                pos += 1;
+            }
+
+            let unit = pos;
+            while pos < bytes.len() && bytes[pos].is_ascii_lowercase() {
+                pos += 1;
+            }
+
+            let index = match &s[unit..pos] {
+                "" if pos == bytes.len() => {
+                    return Err(ParseDurationError::new(Kind::UnexpectedEnd, pos))
+                }
+                "y" | "year" | "years" => 0,
+                "mo" | "month" | "months" => 1,
+                "w" | "week" | "weeks" => 2,
+                "d" | "day" | "days" => 3,
+                _ => return Err(ParseDurationError::new(Kind::UnexpectedCharacter, unit)),
+            };
+
+            if index < next {
+                return Err(ParseDurationError::new(Kind::MisplacedDesignator, unit));
+            }
+
+            values[index] = value as i64;
+            next = index + 1;
         }
+
+        let overflow = ParseDurationError::new(Kind::Overflow, 0);
+        let [years, months, weeks, days] = values;
+        let months = i32::try_from(years * 12 + months).map_err(|_| overflow)?;
+        let days = i32::try_from(weeks * 7 + days).map_err(|_| overflow)?;
+        Ok(Self::new_with_days(0, months, 0, days))
     }
 }
 
@@ -385,6 +737,7 @@ mod tests {
     use time::Month::*;
 
     use crate::ext::NumericCalendarDuration;
+    use crate::{CalendarDuration, DayPolicy, ParseDurationErrorKind as Kind};
 
     #[test]
     fn sub_one() {
@@ -435,6 +788,133 @@ mod tests {
         assert_eq!(d0 + 12.months(), d1);
     }
 
+    #[test]
+    fn add_december() {
+        let d0 = Date::from_calendar_date(2023, November, 30).unwrap();
+        let d1 = Date::from_calendar_date(2023, December, 30).unwrap();
+        assert_eq!(d0 + 1.months(), d1);
+        let d2 = Date::from_calendar_date(2024, December, 30).unwrap();
+        assert_eq!(d1 + 12.months(), d2);
+        assert_eq!(d2 - 12.months(), d1);
+    }
+
+    #[test]
+    fn add_overflow_policy() {
+        let d0 = Date::from_calendar_date(2023, May, 31).unwrap();
+        let d1 = Date::from_calendar_date(2023, July, 1).unwrap();
+        let rs = CalendarDuration::checked_date_add_with(d0, 1.months(), DayPolicy::Overflow);
+        assert_eq!(rs, Some(d1));
+        let rs = CalendarDuration::checked_date_add_with(d0, 2.months(), DayPolicy::Reject);
+        assert_eq!(rs, Some(d0 + 2.months()));
+    }
+
+    #[test]
+    fn add_end_of_month_policy() {
+        let add = |date, months| {
+            CalendarDuration::checked_date_add_with(date, months, DayPolicy::EndOfMonth).unwrap()
+        };
+
+        let d0 = Date::from_calendar_date(2023, January, 31).unwrap();
+        let d1 = Date::from_calendar_date(2023, February, 28).unwrap();
+        let d2 = Date::from_calendar_date(2023, March, 31).unwrap();
+        assert_eq!(add(d0, 1.months()), d1);
+        assert_eq!(add(d1, 1.months()), d2);
+        assert_eq!(add(d2, (-1).months()), d1);
+
+        let d0 = Date::from_calendar_date(2024, February, 29).unwrap();
+        let d1 = Date::from_calendar_date(2025, February, 28).unwrap();
+        let d2 = Date::from_calendar_date(2024, April, 29).unwrap();
+        assert_eq!(add(d0, 12.months()), d1);
+        assert_eq!(add(d1, (-12).months()), d0);
+        assert_eq!(add(d2, 1.months()), d2 + 1.months());
+    }
+
+    #[test]
+    fn between_round_trip() {
+        let dates = [
+            Date::from_calendar_date(2023, January, 31).unwrap(),
+            Date::from_calendar_date(2023, February, 28).unwrap(),
+            Date::from_calendar_date(2024, February, 29).unwrap(),
+            Date::from_calendar_date(2024, March, 30).unwrap(),
+            Date::from_calendar_date(2024, December, 31).unwrap(),
+            Date::from_calendar_date(2025, January, 1).unwrap(),
+        ];
+
+        for d0 in dates {
+            for d1 in dates {
+                let md = CalendarDuration::between(d0, d1);
+                assert_eq!(d0 + md, d1);
+                assert!(md.whole_days().abs() < 31);
+                assert_eq!(md.is_negative(), d1 < d0);
+            }
+        }
+    }
+
+    #[test]
+    fn between_clamp() {
+        let d0 = Date::from_calendar_date(2023, January, 31).unwrap();
+        let d1 = Date::from_calendar_date(2023, February, 28).unwrap();
+        assert_eq!(CalendarDuration::between(d0, d1), 1.months());
+        let d1 = Date::from_calendar_date(2023, February, 27).unwrap();
+        assert_eq!(CalendarDuration::between(d0, d1), 27.days());
+    }
+
+    #[test]
+    fn add_clamp_days() {
+        let d0 = Date::from_calendar_date(2023, January, 31).unwrap();
+        let d1 = Date::from_calendar_date(2023, March, 2).unwrap();
+        assert_eq!(d0 + (1.months() + 2.days()), d1);
+    }
+
+    #[test]
+    fn sub_clamp_days() {
+        let d0 = Date::from_calendar_date(2023, March, 31).unwrap();
+        let d1 = Date::from_calendar_date(2023, February, 14).unwrap();
+        assert_eq!(d0 - (1.months() + 2.weeks()), d1);
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let durations = [
+            0.months(),
+            14.months(),
+            (-14).months(),
+            1.months() - 10.days(),
+            CalendarDuration::MAX,
+            CalendarDuration::MIN,
+        ];
+
+        for d0 in durations {
+            assert_eq!(d0.to_string().parse(), Ok(d0));
+            assert_eq!(format!("{:#}", d0).parse(), Ok(d0));
+        }
+    }
+
+    #[test]
+    fn display_long() {
+        assert_eq!(format!("{:#}", 0.days()), "0 days");
+        assert_eq!(format!("{:#}", (-1).years()), "-1 year");
+        assert_eq!(format!("{:#}", 2.weeks() + 1.days()), "2 weeks 1 day");
+        assert_eq!(format!("{:<8}|", 1.years()), "1y      |");
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let parse_err = |s: &str| {
+            let error = s.parse::<CalendarDuration>().unwrap_err();
+            (error.kind(), error.offset())
+        };
+
+        assert_eq!(parse_err(""), (Kind::UnexpectedEnd, 0));
+        assert_eq!(parse_err("1"), (Kind::UnexpectedEnd, 1));
+        assert_eq!(parse_err("1y "), (Kind::UnexpectedEnd, 3));
+        assert_eq!(parse_err("y"), (Kind::UnexpectedCharacter, 0));
+        assert_eq!(parse_err("1mo 1y"), (Kind::MisplacedDesignator, 5));
+        assert_eq!(parse_err("1y,2mo"), (Kind::UnexpectedCharacter, 2));
+        assert_eq!(parse_err("1 fortnight"), (Kind::UnexpectedCharacter, 2));
+        assert_eq!(parse_err("3000000000d"), (Kind::Overflow, 0));
+    }
+
     #[test]
     fn sub_leap() {
         let d0 = Date::from_calendar_date(2024, February, 29).unwrap();
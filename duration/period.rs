@@ -0,0 +1,261 @@
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use time::{Date, Duration};
+
+use crate::CalendarDuration;
+
+/// A span of time combining calendar months, nominal days and an exact duration.
+///
+/// Components are applied largest unit first: months (with the day clamped
+/// to the length of the resulting month), then days, then the exact duration.
+/// Subtraction applies the negated components in the same order.
+///
+/// The nominal days are kept in the calendar component, so periods with the
+/// same months, days and exact duration are equal however they were created.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Period {
+    calendar: CalendarDuration,
+    time: Duration,
+}
+
+impl Period {
+    /// Creates a new `Period` with provided calendar duration, days and exact duration.
+    ///
+    /// The days are added to the days of the calendar duration.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use timext::{CalendarDuration, Period, ext::NumericCalendarDuration};
+    /// let md = CalendarDuration::new_with_days(0, 1, 0, 3);
+    /// let p0 = Period::new(1.months(), 3, 4.hours());
+    /// assert_eq!(p0.calendar(), md);
+    /// assert_eq!(p0.days(), 3);
+    /// assert_eq!(p0.time(), 4.hours());
+    /// assert_eq!(p0, Period::new(md, 0, 4.hours()));
+    /// ```
+    #[must_use]
+    pub const fn new(calendar: CalendarDuration, days: i32, time: Duration) -> Self {
+        Self {
+            calendar: calendar.with_added_days(days),
+            time,
+        }
+    }
+
+    /// Returns the calendar component of the `Period`, with its months and days.
+    #[must_use]
+    pub const fn calendar(self) -> CalendarDuration {
+        self.calendar
+    }
+
+    /// Returns the nominal days component of the `Period`.
+    #[must_use]
+    pub const fn days(self) -> i32 {
+        self.calendar.whole_days()
+    }
+
+    /// Returns the exact component of the `Period`.
+    #[must_use]
+    pub const fn time(self) -> Duration {
+        self.time
+    }
+
+    /// Checks if all components of the `Period` are zero.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// # use timext::{Period, ext::NumericCalendarDuration};
+    /// assert!(Period::default().is_zero());
+    /// assert!(!Period::new(0.months(), 1, Duration::ZERO).is_zero());
+    /// ```
+    #[must_use]
+    pub const fn is_zero(self) -> bool {
+        self.calendar.is_zero() && self.time.is_zero()
+    }
+}
+
+impl Period {
+    /// Returns the sum of provided `Date` and `Period`.
+    ///
+    /// The sub-day part of the exact component is ignored, as with `Date + Duration`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Duration, Month::{January, March}};
+    /// # use timext::{Period, ext::NumericCalendarDuration};
+    /// let d0 = Date::from_calendar_date(2023, January, 31).unwrap();
+    /// let d1 = Date::from_calendar_date(2023, March, 3).unwrap();
+    /// let p0 = Period::new(1.months(), 3, Duration::ZERO);
+    /// assert_eq!(Period::checked_date_add(d0, p0), Some(d1));
+    /// ```
+    #[must_use]
+    pub fn checked_date_add(date: Date, period: Self) -> Option<Date> {
+        CalendarDuration::checked_date_add(date, period.calendar)?.checked_add(period.time)
+    }
+
+    /// Returns the difference of provided `Date` and `Period`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Duration, Month::{February, March}};
+    /// # use timext::{Period, ext::NumericCalendarDuration};
+    /// let d0 = Date::from_calendar_date(2023, March, 31).unwrap();
+    /// let d1 = Date::from_calendar_date(2023, February, 27).unwrap();
+    /// let p0 = Period::new(1.months(), 1, Duration::ZERO);
+    /// assert_eq!(Period::checked_date_sub(d0, p0), Some(d1));
+    /// ```
+    #[must_use]
+    pub fn checked_date_sub(date: Date, period: Self) -> Option<Date> {
+        let period = period.checked_neg()?;
+        Self::checked_date_add(date, period)
+    }
+}
+
+impl Period {
+    /// Computes `self + rhs`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use timext::{Period, ext::NumericCalendarDuration};
+    /// let p0 = Period::new(1.months(), 2, 3.hours());
+    /// let p1 = Period::new(2.months(), 4, 6.hours());
+    /// assert_eq!(p0.checked_add(p0), Some(p1));
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            calendar: self.calendar.checked_add(rhs.calendar)?,
+            time: self.time.checked_add(rhs.time)?,
+        })
+    }
+
+    /// Computes `self - rhs`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use timext::{Period, ext::NumericCalendarDuration};
+    /// let p0 = Period::new(1.months(), 2, 3.hours());
+    /// assert_eq!(p0.checked_sub(p0), Some(Period::default()));
+    /// ```
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            calendar: self.calendar.checked_sub(rhs.calendar)?,
+            time: self.time.checked_sub(rhs.time)?,
+        })
+    }
+
+    /// Computes `-self`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use timext::{Period, ext::NumericCalendarDuration};
+    /// let p0 = Period::new(1.months(), 2, 3.hours());
+    /// let p1 = Period::new((-1).months(), -2, (-3).hours());
+    /// assert_eq!(p0.checked_neg(), Some(p1));
+    /// ```
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            calendar: self.calendar.checked_neg()?,
+            time: Duration::ZERO.checked_sub(self.time)?,
+        })
+    }
+}
+
+impl From<CalendarDuration> for Period {
+    fn from(calendar: CalendarDuration) -> Self {
+        Self::new(calendar, 0, Duration::ZERO)
+    }
+}
+
+impl From<Duration> for Period {
+    fn from(time: Duration) -> Self {
+        Self::new(CalendarDuration::default(), 0, time)
+    }
+}
+
+impl Add for Period {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("overflow when adding timext::Period")
+    }
+}
+
+impl AddAssign for Period {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Period {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting timext::Period")
+    }
+}
+
+impl SubAssign for Period {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Period {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg()
+            .expect("overflow when negating timext::Period")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::ext::NumericalDuration;
+    use time::Month::*;
+    use time::{Date, Duration, UtcOffset};
+
+    use crate::ext::NumericCalendarDuration;
+    use crate::{CalendarDuration, Period};
+
+    #[test]
+    fn add_order() {
+        let d0 = Date::from_calendar_date(2023, January, 31).unwrap();
+        let d1 = Date::from_calendar_date(2023, March, 1).unwrap();
+        assert_eq!(d0 + Period::new(1.months(), 1, Duration::ZERO), d1);
+    }
+
+    #[test]
+    fn sub_order() {
+        let d0 = Date::from_calendar_date(2023, March, 31).unwrap();
+        let d1 = Date::from_calendar_date(2023, February, 27).unwrap();
+        assert_eq!(d0 - Period::new(1.months(), 1, Duration::ZERO), d1);
+    }
+
+    #[test]
+    fn days_in_calendar() {
+        let p0 = Period::new(CalendarDuration::days(1), 0, Duration::ZERO);
+        let p1 = Period::new(0.months(), 1, Duration::ZERO);
+        assert_eq!(p0, p1);
+        assert_eq!(p1.calendar(), CalendarDuration::days(1));
+        assert_eq!((p0 - p1).days(), 0);
+    }
+
+    #[test]
+    fn add_datetime() {
+        let d0 = Date::from_calendar_date(2023, January, 31).unwrap();
+        let d0 = d0.with_hms(22, 0, 0).unwrap();
+        let d1 = Date::from_calendar_date(2023, March, 4).unwrap();
+        let d1 = d1.with_hms(2, 0, 0).unwrap();
+        assert_eq!(d0 + Period::new(1.months(), 3, 4.hours()), d1);
+    }
+
+    #[test]
+    fn add_offset_datetime() {
+        let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+        let d0 = Date::from_calendar_date(2024, February, 29).unwrap();
+        let d0 = d0.with_hms(12, 0, 0).unwrap().assume_offset(offset);
+        let d1 = Date::from_calendar_date(2025, March, 1).unwrap();
+        let d1 = d1.with_hms(11, 30, 0).unwrap().assume_offset(offset);
+        assert_eq!(d0 + Period::new(1.years(), 1, (-30).minutes()), d1);
+    }
+}
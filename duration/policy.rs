@@ -0,0 +1,22 @@
+/// Resolution of a day of the month that does not exist in the resulting month.
+///
+/// Used by [`CalendarDuration::checked_date_add_with`] and similar methods,
+/// e.g. when adding a month to January 31.
+///
+/// [`CalendarDuration::checked_date_add_with`]: crate::CalendarDuration::checked_date_add_with
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayPolicy {
+    /// Clamps the day to the last day of the month, e.g. Jan 31 + 1 month = Feb 28.
+    #[default]
+    Clamp,
+    /// Carries the excess days into the next month, e.g. Jan 31 + 1 month = Mar 3.
+    Overflow,
+    /// Fails if the day does not exist in the month.
+    Reject,
+    /// Maps the last day of a month to the last day of the resulting month,
+    /// e.g. Feb 28 + 1 month = Mar 31, and clamps any other day.
+    ///
+    /// Mirrors the `ADD_MONTHS` function of Oracle and keeps monthly series that
+    /// start at the end of a month from drifting.
+    EndOfMonth,
+}
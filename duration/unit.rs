@@ -0,0 +1,70 @@
+use time::Month;
+
+use crate::CalendarDuration;
+
+/// A calendar unit spanning whole months, aligned to the start of the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CalendarUnit {
+    /// A month.
+    Month,
+    /// A quarter, starting in January, April, July or October.
+    Quarter,
+    /// A half-year, starting in January or July.
+    HalfYear,
+    /// A year.
+    Year,
+}
+
+impl CalendarUnit {
+    /// Returns the number of months in the unit.
+    ///
+    /// ```rust
+    /// # use timext::CalendarUnit;
+    /// assert_eq!(CalendarUnit::Quarter.months(), 3);
+    /// assert_eq!(CalendarUnit::Year.months(), 12);
+    /// ```
+    #[must_use]
+    pub const fn months(self) -> u8 {
+        match self {
+            Self::Month => 1,
+            Self::Quarter => 3,
+            Self::HalfYear => 6,
+            Self::Year => 12,
+        }
+    }
+
+    /// Returns the first month of the unit that contains the provided month.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// # use timext::CalendarUnit;
+    /// assert_eq!(CalendarUnit::Quarter.first_month(Month::May), Month::April);
+    /// assert_eq!(CalendarUnit::HalfYear.first_month(Month::May), Month::January);
+    /// ```
+    #[must_use]
+    pub fn first_month(self, month: Month) -> Month {
+        let month = month as u8 - 1;
+        let month = month - month % self.months();
+        Month::try_from(month + 1).unwrap()
+    }
+}
+
+impl From<CalendarUnit> for CalendarDuration {
+    fn from(unit: CalendarUnit) -> Self {
+        Self::months(unit.months() as i32)
+    }
+}
+
+/// A rounding mode used by [`BoundaryExtension::round_to`].
+///
+/// [`BoundaryExtension::round_to`]: crate::ext::BoundaryExtension::round_to
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Rounds down to the start of the unit.
+    #[default]
+    Floor,
+    /// Rounds up to the start of the next unit, unless already at a start.
+    Ceil,
+    /// Rounds to the nearest start, rounding up at exactly half of the unit.
+    HalfUp,
+}
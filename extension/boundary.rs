@@ -0,0 +1,195 @@
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+use crate::ext::CalendarExtension;
+use crate::util::days_in_year_month;
+use crate::{CalendarUnit, RoundMode};
+
+/// Enable truncation and rounding to [`CalendarUnit`]s.
+pub trait BoundaryExtension: CalendarExtension {
+    /// Returns the first instant of the unit, e.g. the first day of the month at 00:00.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{CalendarUnit, ext::BoundaryExtension};
+    /// let d0 = Date::from_calendar_date(2023, May, 17).unwrap();
+    /// let d1 = Date::from_calendar_date(2023, April, 1).unwrap();
+    /// assert_eq!(d0.start_of(CalendarUnit::Quarter), d1);
+    /// ```
+    #[must_use]
+    fn start_of(self, unit: CalendarUnit) -> Self;
+
+    /// Returns the last instant of the unit, e.g. the last day of the month at 23:59:59.999999999.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{CalendarUnit, ext::BoundaryExtension};
+    /// let d0 = Date::from_calendar_date(2024, January, 17).unwrap();
+    /// let d1 = Date::from_calendar_date(2024, June, 30).unwrap();
+    /// assert_eq!(d0.end_of(CalendarUnit::HalfYear), d1);
+    /// ```
+    #[must_use]
+    fn end_of(self, unit: CalendarUnit) -> Self;
+
+    /// Rounds to the start of the unit with the provided [`RoundMode`],
+    /// returning `None` if the result is out of range.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{CalendarUnit, RoundMode, ext::BoundaryExtension};
+    /// let d0 = Date::from_calendar_date(2023, January, 17).unwrap();
+    /// let d1 = Date::from_calendar_date(2023, February, 1).unwrap();
+    /// assert_eq!(d0.round_to(CalendarUnit::Month, RoundMode::HalfUp), Some(d1));
+    /// ```
+    #[must_use]
+    fn round_to(self, unit: CalendarUnit, mode: RoundMode) -> Option<Self>;
+}
+
+impl BoundaryExtension for Date {
+    fn start_of(self, unit: CalendarUnit) -> Self {
+        let month = unit.first_month(self.month());
+        Self::from_calendar_date(self.year(), month, 1).unwrap()
+    }
+
+    fn end_of(self, unit: CalendarUnit) -> Self {
+        let month = unit.first_month(self.month()) as u8 + unit.months() - 1;
+        let month = Month::try_from(month).unwrap();
+        let day = days_in_year_month(self.year(), month);
+        Self::from_calendar_date(self.year(), month, day).unwrap()
+    }
+
+    fn round_to(self, unit: CalendarUnit, mode: RoundMode) -> Option<Self> {
+        let start = self.start_of(unit);
+        let next = || self.end_of(unit).next_day();
+        match mode {
+            RoundMode::Floor => Some(start),
+            RoundMode::Ceil if self == start => Some(self),
+            RoundMode::Ceil => next(),
+            RoundMode::HalfUp => {
+                // The length of the unit, as the next start may be out of range.
+                let length = self.end_of(unit) - start + Duration::DAY;
+                match (self - start) * 2 >= length {
+                    true => next(),
+                    false => Some(start),
+                }
+            }
+        }
+    }
+}
+
+impl BoundaryExtension for PrimitiveDateTime {
+    fn start_of(self, unit: CalendarUnit) -> Self {
+        Self::new(self.date().start_of(unit), Time::MIDNIGHT)
+    }
+
+    fn end_of(self, unit: CalendarUnit) -> Self {
+        let time = Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap();
+        Self::new(self.date().end_of(unit), time)
+    }
+
+    fn round_to(self, unit: CalendarUnit, mode: RoundMode) -> Option<Self> {
+        let start = self.start_of(unit);
+        let next = || {
+            let date = self.date().end_of(unit).next_day()?;
+            Some(Self::new(date, Time::MIDNIGHT))
+        };
+
+        match mode {
+            RoundMode::Floor => Some(start),
+            RoundMode::Ceil if self == start => Some(self),
+            RoundMode::Ceil => next(),
+            RoundMode::HalfUp => {
+                let length = self.date().end_of(unit) - start.date() + Duration::DAY;
+                match (self - start) * 2 >= length {
+                    true => next(),
+                    false => Some(start),
+                }
+            }
+        }
+    }
+}
+
+impl BoundaryExtension for OffsetDateTime {
+    fn start_of(self, unit: CalendarUnit) -> Self {
+        let datetime = PrimitiveDateTime::new(self.date(), self.time());
+        datetime.start_of(unit).assume_offset(self.offset())
+    }
+
+    fn end_of(self, unit: CalendarUnit) -> Self {
+        let datetime = PrimitiveDateTime::new(self.date(), self.time());
+        datetime.end_of(unit).assume_offset(self.offset())
+    }
+
+    fn round_to(self, unit: CalendarUnit, mode: RoundMode) -> Option<Self> {
+        let datetime = PrimitiveDateTime::new(self.date(), self.time());
+        let datetime = datetime.round_to(unit, mode)?;
+        Some(datetime.assume_offset(self.offset()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::{Date, Duration, UtcOffset};
+
+    use crate::ext::BoundaryExtension;
+    use crate::{CalendarUnit::*, RoundMode::*};
+
+    #[test]
+    fn boundaries() {
+        let d0 = Date::from_calendar_date(2024, February, 10).unwrap();
+        let d1 = Date::from_calendar_date(2024, February, 29).unwrap();
+        let d2 = Date::from_calendar_date(2024, March, 31).unwrap();
+        let d3 = Date::from_calendar_date(2024, December, 31).unwrap();
+        let d4 = Date::from_calendar_date(2024, July, 1).unwrap();
+        assert_eq!(d0.end_of(Month), d1);
+        assert_eq!(d0.end_of(Quarter), d2);
+        assert_eq!(d0.end_of(Year), d3);
+        assert_eq!(d3.start_of(HalfYear), d4);
+        assert_eq!(Date::MAX.end_of(Year), Date::MAX);
+    }
+
+    #[test]
+    fn datetime_boundaries() {
+        let d0 = Date::from_calendar_date(2024, November, 10).unwrap();
+        let d1 = Date::from_calendar_date(2024, October, 1).unwrap();
+        let d2 = Date::from_calendar_date(2025, January, 1).unwrap();
+
+        let t0 = d0.with_hms(10, 30, 0).unwrap();
+        assert_eq!(t0.start_of(Quarter), d1.midnight());
+        assert_eq!(t0.end_of(Quarter) + Duration::NANOSECOND, d2.midnight());
+    }
+
+    #[test]
+    fn rounding() {
+        let d0 = Date::from_calendar_date(2023, May, 1).unwrap();
+        let d1 = Date::from_calendar_date(2023, April, 1).unwrap();
+        let d2 = Date::from_calendar_date(2023, July, 1).unwrap();
+        assert_eq!(d0.round_to(Quarter, Floor), Some(d1));
+        assert_eq!(d0.round_to(Quarter, Ceil), Some(d2));
+        assert_eq!(d0.round_to(Quarter, HalfUp), Some(d1));
+        assert_eq!(d0.round_to(Month, Ceil), Some(d0));
+        assert_eq!(Date::MAX.round_to(Year, Ceil), None);
+
+        let d3 = Date::from_calendar_date(Date::MAX.year(), January, 2).unwrap();
+        let d4 = Date::from_calendar_date(Date::MAX.year(), January, 1).unwrap();
+        assert_eq!(d3.round_to(Year, HalfUp), Some(d4));
+        assert_eq!(d3.midnight().round_to(Year, HalfUp), Some(d4.midnight()));
+        assert_eq!(Date::MAX.round_to(Year, HalfUp), None);
+    }
+
+    #[test]
+    fn datetime_rounding() {
+        let offset = UtcOffset::from_hms(3, 0, 0).unwrap();
+        let d0 = Date::from_calendar_date(2023, February, 15).unwrap();
+        let d1 = Date::from_calendar_date(2023, February, 1).unwrap();
+        let d2 = Date::from_calendar_date(2023, March, 1).unwrap();
+        let d1 = d1.midnight().assume_offset(offset);
+        let d2 = d2.midnight().assume_offset(offset);
+
+        let t0 = d0.midnight().assume_offset(offset);
+        assert_eq!(t0.round_to(Month, HalfUp), Some(d2));
+        let t0 = t0 - Duration::NANOSECOND;
+        assert_eq!(t0.round_to(Month, HalfUp), Some(d1));
+        assert_eq!(d1.round_to(Month, Ceil), Some(d1));
+    }
+}
@@ -0,0 +1,204 @@
+use time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+use crate::ext::CalendarExtension;
+use crate::{BusinessCalendar, BusinessDayConvention, CalendarDuration};
+
+/// Enable business day arithmetic against a [`BusinessCalendar`].
+///
+/// The time of day and the offset are preserved.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::{BusinessCalendar, WeekendMask};
+/// # use timext::ext::{BusinessExtension, CalendarExtension, NumericCalendarDuration};
+/// let calendar = BusinessCalendar::new(WeekendMask::SATURDAY_SUNDAY);
+/// let d0 = Date::from_calendar_date(2024, May, 31).unwrap();
+/// let d1 = Date::from_calendar_date(2024, July, 1).unwrap();
+/// let d2 = d0.checked_calendar_add(1.months()).unwrap();
+/// assert_eq!(d2.roll_forward(&calendar), Some(d1));
+/// ```
+pub trait BusinessExtension: CalendarExtension {
+    /// Returns the first business day strictly after the date.
+    #[must_use]
+    fn next_business_day(self, calendar: &BusinessCalendar) -> Option<Self>;
+
+    /// Returns the last business day strictly before the date.
+    #[must_use]
+    fn previous_business_day(self, calendar: &BusinessCalendar) -> Option<Self>;
+
+    /// Returns the date if it is a business day, or the next business day.
+    #[must_use]
+    fn roll_forward(self, calendar: &BusinessCalendar) -> Option<Self>;
+
+    /// Returns the date if it is a business day, or the previous business day.
+    #[must_use]
+    fn roll_backward(self, calendar: &BusinessCalendar) -> Option<Self>;
+
+    /// Shifts the date by the provided number of business days.
+    #[must_use]
+    fn checked_add_business_days(self, days: i64, calendar: &BusinessCalendar) -> Option<Self>;
+
+    /// Adjusts the date to a business day with the provided [`BusinessDayConvention`].
+    #[must_use]
+    fn adjust(self, convention: BusinessDayConvention, calendar: &BusinessCalendar)
+        -> Option<Self>;
+
+    /// Adds the duration and adjusts the result to a business day, optionally
+    /// applying the end-of-month rule.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{BusinessCalendar, BusinessDayConvention, WeekendMask};
+    /// # use timext::ext::{BusinessExtension, NumericCalendarDuration};
+    /// let calendar = BusinessCalendar::new(WeekendMask::SATURDAY_SUNDAY);
+    /// let convention = BusinessDayConvention::Following;
+    /// let d0 = Date::from_calendar_date(2024, May, 31).unwrap();
+    /// let d1 = Date::from_calendar_date(2024, July, 1).unwrap();
+    /// let d2 = d0.checked_calendar_add_adjusted(1.months(), convention, false, &calendar);
+    /// assert_eq!(d2, Some(d1));
+    /// ```
+    #[must_use]
+    fn checked_calendar_add_adjusted(
+        self,
+        duration: CalendarDuration,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+        calendar: &BusinessCalendar,
+    ) -> Option<Self>;
+}
+
+impl BusinessExtension for Date {
+    fn next_business_day(self, calendar: &BusinessCalendar) -> Option<Self> {
+        calendar.next_business_day(self)
+    }
+
+    fn previous_business_day(self, calendar: &BusinessCalendar) -> Option<Self> {
+        calendar.previous_business_day(self)
+    }
+
+    fn roll_forward(self, calendar: &BusinessCalendar) -> Option<Self> {
+        calendar.roll_forward(self)
+    }
+
+    fn roll_backward(self, calendar: &BusinessCalendar) -> Option<Self> {
+        calendar.roll_backward(self)
+    }
+
+    fn checked_add_business_days(self, days: i64, calendar: &BusinessCalendar) -> Option<Self> {
+        calendar.checked_add_business_days(self, days)
+    }
+
+    fn adjust(
+        self,
+        convention: BusinessDayConvention,
+        calendar: &BusinessCalendar,
+    ) -> Option<Self> {
+        convention.adjust(self, calendar)
+    }
+
+    fn checked_calendar_add_adjusted(
+        self,
+        duration: CalendarDuration,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+        calendar: &BusinessCalendar,
+    ) -> Option<Self> {
+        calendar.checked_add_adjusted(self, duration, convention, end_of_month)
+    }
+}
+
+impl BusinessExtension for PrimitiveDateTime {
+    fn next_business_day(self, calendar: &BusinessCalendar) -> Option<Self> {
+        let date = calendar.next_business_day(self.date())?;
+        Some(self.replace_date(date))
+    }
+
+    fn previous_business_day(self, calendar: &BusinessCalendar) -> Option<Self> {
+        let date = calendar.previous_business_day(self.date())?;
+        Some(self.replace_date(date))
+    }
+
+    fn roll_forward(self, calendar: &BusinessCalendar) -> Option<Self> {
+        let date = calendar.roll_forward(self.date())?;
+        Some(self.replace_date(date))
+    }
+
+    fn roll_backward(self, calendar: &BusinessCalendar) -> Option<Self> {
+        let date = calendar.roll_backward(self.date())?;
+        Some(self.replace_date(date))
+    }
+
+    fn checked_add_business_days(self, days: i64, calendar: &BusinessCalendar) -> Option<Self> {
+        let date = calendar.checked_add_business_days(self.date(), days)?;
+        Some(self.replace_date(date))
+    }
+
+    fn adjust(
+        self,
+        convention: BusinessDayConvention,
+        calendar: &BusinessCalendar,
+    ) -> Option<Self> {
+        let date = convention.adjust(self.date(), calendar)?;
+        Some(self.replace_date(date))
+    }
+
+    fn checked_calendar_add_adjusted(
+        self,
+        duration: CalendarDuration,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+        calendar: &BusinessCalendar,
+    ) -> Option<Self> {
+        let date = self.date();
+        let date = calendar.checked_add_adjusted(date, duration, convention, end_of_month)?;
+        Some(self.replace_date(date))
+    }
+}
+
+impl BusinessExtension for OffsetDateTime {
+    fn next_business_day(self, calendar: &BusinessCalendar) -> Option<Self> {
+        let date = calendar.next_business_day(self.date())?;
+        Some(self.replace_date(date))
+    }
+
+    fn previous_business_day(self, calendar: &BusinessCalendar) -> Option<Self> {
+        let date = calendar.previous_business_day(self.date())?;
+        Some(self.replace_date(date))
+    }
+
+    fn roll_forward(self, calendar: &BusinessCalendar) -> Option<Self> {
+        let date = calendar.roll_forward(self.date())?;
+        Some(self.replace_date(date))
+    }
+
+    fn roll_backward(self, calendar: &BusinessCalendar) -> Option<Self> {
+        let date = calendar.roll_backward(self.date())?;
+        Some(self.replace_date(date))
+    }
+
+    fn checked_add_business_days(self, days: i64, calendar: &BusinessCalendar) -> Option<Self> {
+        let date = calendar.checked_add_business_days(self.date(), days)?;
+        Some(self.replace_date(date))
+    }
+
+    fn adjust(
+        self,
+        convention: BusinessDayConvention,
+        calendar: &BusinessCalendar,
+    ) -> Option<Self> {
+        let date = convention.adjust(self.date(), calendar)?;
+        Some(self.replace_date(date))
+    }
+
+    fn checked_calendar_add_adjusted(
+        self,
+        duration: CalendarDuration,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+        calendar: &BusinessCalendar,
+    ) -> Option<Self> {
+        let date = self.date();
+        let date = calendar.checked_add_adjusted(date, duration, convention, end_of_month)?;
+        Some(self.replace_date(date))
+    }
+}
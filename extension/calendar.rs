@@ -2,7 +2,7 @@ use std::ops::{Add, AddAssign, Sub, SubAssign};
 
 use time::{Date, OffsetDateTime, PrimitiveDateTime};
 
-use crate::CalendarDuration;
+use crate::{CalendarDuration, DayPolicy};
 
 /// Sealed trait to prevent downstream implementations.
 mod sealed {
@@ -24,6 +24,22 @@ pub trait CalendarExtension: sealed::Sealed + Sized {
     fn saturating_calendar_add(self, duration: CalendarDuration) -> Self;
     #[must_use]
     fn saturating_calendar_sub(self, duration: CalendarDuration) -> Self;
+    #[must_use]
+    fn checked_calendar_add_with(
+        self,
+        duration: CalendarDuration,
+        policy: DayPolicy,
+    ) -> Option<Self>;
+    #[must_use]
+    fn checked_calendar_sub_with(
+        self,
+        duration: CalendarDuration,
+        policy: DayPolicy,
+    ) -> Option<Self>;
+    #[must_use]
+    fn calendar_until(self, end: Self) -> CalendarDuration;
+    #[must_use]
+    fn checked_calendar_until(self, end: Self) -> Option<CalendarDuration>;
 }
 
 impl CalendarExtension for Date {
@@ -56,6 +72,30 @@ impl CalendarExtension for Date {
             Self::MIN
         }
     }
+
+    fn checked_calendar_add_with(
+        self,
+        duration: CalendarDuration,
+        policy: DayPolicy,
+    ) -> Option<Self> {
+        CalendarDuration::checked_date_add_with(self, duration, policy)
+    }
+
+    fn checked_calendar_sub_with(
+        self,
+        duration: CalendarDuration,
+        policy: DayPolicy,
+    ) -> Option<Self> {
+        CalendarDuration::checked_date_sub_with(self, duration, policy)
+    }
+
+    fn calendar_until(self, end: Self) -> CalendarDuration {
+        CalendarDuration::between(self, end)
+    }
+
+    fn checked_calendar_until(self, end: Self) -> Option<CalendarDuration> {
+        Some(self.calendar_until(end))
+    }
 }
 
 impl Add<CalendarDuration> for Date {
@@ -120,6 +160,40 @@ impl CalendarExtension for PrimitiveDateTime {
             Self::MIN
         }
     }
+
+    fn checked_calendar_add_with(
+        self,
+        duration: CalendarDuration,
+        policy: DayPolicy,
+    ) -> Option<Self> {
+        let date = self.date().checked_calendar_add_with(duration, policy)?;
+        Some(self.replace_date(date))
+    }
+
+    fn checked_calendar_sub_with(
+        self,
+        duration: CalendarDuration,
+        policy: DayPolicy,
+    ) -> Option<Self> {
+        let date = self.date().checked_calendar_sub_with(duration, policy)?;
+        Some(self.replace_date(date))
+    }
+
+    fn calendar_until(self, end: Self) -> CalendarDuration {
+        self.checked_calendar_until(end)
+            .expect("resulting value is out of range")
+    }
+
+    fn checked_calendar_until(self, end: Self) -> Option<CalendarDuration> {
+        // Exclude the last day if the time of day would step past the end.
+        let date = match (self.date(), end.date()) {
+            (x, y) if x < y && self.time() > end.time() => y.previous_day(),
+            (x, y) if x > y && self.time() < end.time() => y.next_day(),
+            (_, y) => Some(y),
+        };
+
+        Some(CalendarDuration::between(self.date(), date?))
+    }
 }
 
 impl Add<CalendarDuration> for PrimitiveDateTime {
@@ -184,15 +258,108 @@ impl CalendarExtension for OffsetDateTime {
             PrimitiveDateTime::MIN.assume_offset(self.offset())
         }
     }
+
+    fn checked_calendar_add_with(
+        self,
+        duration: CalendarDuration,
+        policy: DayPolicy,
+    ) -> Option<Self> {
+        let date = self.date().checked_calendar_add_with(duration, policy)?;
+        Some(self.replace_date(date))
+    }
+
+    fn checked_calendar_sub_with(
+        self,
+        duration: CalendarDuration,
+        policy: DayPolicy,
+    ) -> Option<Self> {
+        let date = self.date().checked_calendar_sub_with(duration, policy)?;
+        Some(self.replace_date(date))
+    }
+
+    fn calendar_until(self, end: Self) -> CalendarDuration {
+        self.checked_calendar_until(end)
+            .expect("resulting value is out of range")
+    }
+
+    fn checked_calendar_until(self, end: Self) -> Option<CalendarDuration> {
+        // The end in the offset of the start, or `None` if it is out of range.
+        let this = PrimitiveDateTime::new(self.date(), self.time());
+        this.checked_calendar_until(this.checked_add(end - self)?)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use time::Date;
     use time::Month::*;
+    use time::{Date, UtcOffset};
 
     use crate::ext::CalendarExtension;
-    use crate::CalendarDuration;
+    use crate::{CalendarDuration, DayPolicy};
+
+    #[test]
+    fn add_with_policy() {
+        let d0 = Date::from_calendar_date(2024, January, 31).unwrap();
+        let d0 = d0.with_hms(12, 0, 0).unwrap();
+        let d1 = Date::from_calendar_date(2024, March, 2).unwrap();
+        let d1 = d1.with_hms(12, 0, 0).unwrap();
+        let md = CalendarDuration::months(1);
+        assert_eq!(
+            d0.checked_calendar_add_with(md, DayPolicy::Overflow),
+            Some(d1)
+        );
+        assert_eq!(d0.checked_calendar_add_with(md, DayPolicy::Reject), None);
+
+        let d2 = Date::from_calendar_date(2024, February, 29).unwrap();
+        let d2 = d2.with_hms(12, 0, 0).unwrap();
+        let d3 = Date::from_calendar_date(2024, March, 31).unwrap();
+        let d3 = d3.with_hms(12, 0, 0).unwrap();
+        assert_eq!(
+            d2.checked_calendar_add_with(md, DayPolicy::EndOfMonth),
+            Some(d3)
+        );
+        assert_eq!(
+            d3.checked_calendar_sub_with(md, DayPolicy::EndOfMonth),
+            Some(d2)
+        );
+    }
+
+    #[test]
+    fn until_datetime() {
+        let d0 = Date::from_calendar_date(2024, January, 31).unwrap();
+        let d1 = Date::from_calendar_date(2024, March, 1).unwrap();
+
+        let t0 = d0.with_hms(12, 0, 0).unwrap();
+        let t1 = d1.with_hms(11, 0, 0).unwrap();
+        let md = CalendarDuration::new(0, 1);
+        assert_eq!(t0.calendar_until(t1), md);
+        assert!(t0 + md <= t1);
+
+        let t1 = d1.with_hms(12, 0, 0).unwrap();
+        let md = CalendarDuration::new_with_days(0, 1, 0, 1);
+        assert_eq!(t0.calendar_until(t1), md);
+        assert_eq!(t0 + md, t1);
+
+        let t0 = d1.with_hms(11, 0, 0).unwrap();
+        let t1 = d0.with_hms(12, 0, 0).unwrap();
+        let md = CalendarDuration::new(0, -1);
+        assert_eq!(t0.calendar_until(t1), md);
+        assert!(t0 + md >= t1);
+    }
+
+    #[test]
+    fn until_offset() {
+        let offset = UtcOffset::from_hms(-5, 0, 0).unwrap();
+        let d0 = Date::from_calendar_date(2024, January, 31).unwrap();
+        let d1 = Date::from_calendar_date(2024, March, 1).unwrap();
+        let t0 = d0.with_hms(12, 0, 0).unwrap().assume_offset(offset);
+        let t1 = d1.with_hms(17, 0, 0).unwrap().assume_utc();
+        let md = CalendarDuration::new_with_days(0, 1, 0, 1);
+        assert_eq!(t0.calendar_until(t1), md);
+
+        let t1 = Date::MIN.midnight().assume_utc();
+        assert_eq!(t0.checked_calendar_until(t1), None);
+    }
 
     #[test]
     fn add_underflow() {
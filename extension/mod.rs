@@ -3,3 +3,12 @@ pub use calendar::*;
 
 mod numeric;
 pub use numeric::*;
+
+mod period;
+pub use period::*;
+
+mod boundary;
+pub use boundary::*;
+
+mod business;
+pub use business::*;
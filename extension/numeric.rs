@@ -9,11 +9,35 @@ mod sealed {
 
 /// Create [`CalendarDuration`]s from numeric literals.
 pub trait NumericCalendarDuration: sealed::Sealed {
+    fn days(self) -> CalendarDuration;
+    fn weeks(self) -> CalendarDuration;
     fn months(self) -> CalendarDuration;
     fn years(self) -> CalendarDuration;
 }
 
 impl NumericCalendarDuration for i64 {
+    /// Creates a new `CalendarDuration` with provided days.
+    ///
+    /// ```rust
+    /// # use timext::{CalendarDuration, ext::NumericCalendarDuration};
+    /// assert_eq!(1.days(), CalendarDuration::days(1));
+    /// assert_eq!((-1).days(), CalendarDuration::days(-1));
+    /// ```
+    fn days(self) -> CalendarDuration {
+        CalendarDuration::days(self as i32)
+    }
+
+    /// Creates a new `CalendarDuration` with provided weeks.
+    ///
+    /// ```rust
+    /// # use timext::{CalendarDuration, ext::NumericCalendarDuration};
+    /// assert_eq!(1.weeks(), CalendarDuration::weeks(1));
+    /// assert_eq!((-1).weeks(), CalendarDuration::weeks(-1));
+    /// ```
+    fn weeks(self) -> CalendarDuration {
+        CalendarDuration::weeks(self as i32)
+    }
+
     /// Creates a new `CalendarDuration` with provided months.
     ///
     /// ```rust
@@ -38,6 +62,28 @@ impl NumericCalendarDuration for i64 {
 }
 
 impl NumericCalendarDuration for f64 {
+    /// Creates a new `CalendarDuration` with provided days.
+    ///
+    /// ```rust
+    /// # use timext::{CalendarDuration, ext::NumericCalendarDuration};
+    /// assert_eq!((1.0).days(), CalendarDuration::days(1));
+    /// assert_eq!((-1.0).days(), CalendarDuration::days(-1));
+    /// ```
+    fn days(self) -> CalendarDuration {
+        i64::days(self as _)
+    }
+
+    /// Creates a new `CalendarDuration` with provided weeks.
+    ///
+    /// ```rust
+    /// # use timext::{CalendarDuration, ext::NumericCalendarDuration};
+    /// assert_eq!((1.5).weeks(), CalendarDuration::days(10));
+    /// assert_eq!((-1.5).weeks(), CalendarDuration::days(-10));
+    /// ```
+    fn weeks(self) -> CalendarDuration {
+        i64::days((self * 7.0) as _)
+    }
+
     /// Creates a new `CalendarDuration` with provided months.
     ///
     /// ```rust
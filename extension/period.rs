@@ -0,0 +1,118 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+use crate::ext::CalendarExtension;
+use crate::Period;
+
+/// Enable arithmetic operations with [`Period`].
+///
+/// Components are applied largest unit first, see [`Period`].
+pub trait PeriodExtension: CalendarExtension {
+    #[must_use]
+    fn checked_period_add(self, period: Period) -> Option<Self>;
+    #[must_use]
+    fn checked_period_sub(self, period: Period) -> Option<Self>;
+    #[must_use]
+    fn saturating_period_add(self, period: Period) -> Self;
+    #[must_use]
+    fn saturating_period_sub(self, period: Period) -> Self;
+}
+
+impl PeriodExtension for Date {
+    fn checked_period_add(self, period: Period) -> Option<Self> {
+        Period::checked_date_add(self, period)
+    }
+
+    fn checked_period_sub(self, period: Period) -> Option<Self> {
+        Period::checked_date_sub(self, period)
+    }
+
+    fn saturating_period_add(self, period: Period) -> Self {
+        self.saturating_calendar_add(period.calendar())
+            .saturating_add(period.time())
+    }
+
+    fn saturating_period_sub(self, period: Period) -> Self {
+        self.saturating_calendar_sub(period.calendar())
+            .saturating_sub(period.time())
+    }
+}
+
+impl PeriodExtension for PrimitiveDateTime {
+    fn checked_period_add(self, period: Period) -> Option<Self> {
+        self.checked_calendar_add(period.calendar())?
+            .checked_add(period.time())
+    }
+
+    fn checked_period_sub(self, period: Period) -> Option<Self> {
+        self.checked_period_add(period.checked_neg()?)
+    }
+
+    fn saturating_period_add(self, period: Period) -> Self {
+        self.saturating_calendar_add(period.calendar())
+            .saturating_add(period.time())
+    }
+
+    fn saturating_period_sub(self, period: Period) -> Self {
+        self.saturating_calendar_sub(period.calendar())
+            .saturating_sub(period.time())
+    }
+}
+
+impl PeriodExtension for OffsetDateTime {
+    fn checked_period_add(self, period: Period) -> Option<Self> {
+        self.checked_calendar_add(period.calendar())?
+            .checked_add(period.time())
+    }
+
+    fn checked_period_sub(self, period: Period) -> Option<Self> {
+        self.checked_period_add(period.checked_neg()?)
+    }
+
+    fn saturating_period_add(self, period: Period) -> Self {
+        self.saturating_calendar_add(period.calendar())
+            .saturating_add(period.time())
+    }
+
+    fn saturating_period_sub(self, period: Period) -> Self {
+        self.saturating_calendar_sub(period.calendar())
+            .saturating_sub(period.time())
+    }
+}
+
+macro_rules! impl_ops {
+    ($($t:ty),+) => {$(
+        impl Add<Period> for $t {
+            type Output = Self;
+
+            fn add(self, rhs: Period) -> Self::Output {
+                self.checked_period_add(rhs)
+                    .expect("resulting value is out of range")
+            }
+        }
+
+        impl AddAssign<Period> for $t {
+            fn add_assign(&mut self, rhs: Period) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl Sub<Period> for $t {
+            type Output = Self;
+
+            fn sub(self, rhs: Period) -> Self::Output {
+                self.checked_period_sub(rhs)
+                    .expect("resulting value is out of range")
+            }
+        }
+
+        impl SubAssign<Period> for $t {
+            fn sub_assign(&mut self, rhs: Period) {
+                *self = *self - rhs;
+            }
+        }
+    )+};
+}
+
+impl_ops![Date, PrimitiveDateTime, OffsetDateTime];
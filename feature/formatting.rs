@@ -0,0 +1,108 @@
+use std::fmt::{Result as FmtResult, Write};
+
+use crate::CalendarDuration;
+
+impl CalendarDuration {
+    /// Formats the `CalendarDuration` as an ISO 8601 duration, e.g. `P1Y2M`, `-P3M` or `P0M`.
+    ///
+    /// Weeks are written as days. If the months and days have different signs,
+    /// each value carries its own sign, e.g. `P1M-1D`. The output is accepted
+    /// by `CalendarDuration::parse_iso8601`.
+    ///
+    /// ```rust
+    /// # use timext::ext::NumericCalendarDuration;
+    /// assert_eq!(14.months().format_iso8601(), "P1Y2M");
+    /// assert_eq!((-3).months().format_iso8601(), "-P3M");
+    /// assert_eq!(0.months().format_iso8601(), "P0M");
+    /// assert_eq!((1.months() + 2.weeks()).format_iso8601(), "P1M14D");
+    /// assert_eq!((1.months() - 1.days()).format_iso8601(), "P1M-1D");
+    /// ```
+    #[must_use]
+    pub fn format_iso8601(self) -> String {
+        let mut output = String::new();
+        self.format_iso8601_into(&mut output)
+            .expect("writing to a `String` never fails");
+        output
+    }
+
+    /// Writes the `CalendarDuration` as an ISO 8601 duration into the provided output.
+    ///
+    /// See [`CalendarDuration::format_iso8601`] for the format.
+    ///
+    /// ```rust
+    /// # use std::fmt::Write;
+    /// # use timext::ext::NumericCalendarDuration;
+    /// let mut output = String::from("duration=");
+    /// 14.months().format_iso8601_into(&mut output).unwrap();
+    /// assert_eq!(output, "duration=P1Y2M");
+    /// ```
+    pub fn format_iso8601_into(self, output: &mut impl Write) -> FmtResult {
+        if self.is_zero() {
+            return output.write_str("P0M");
+        }
+
+        let (months, days) = (self.whole_months(), self.whole_days());
+        let negative = months <= 0 && days <= 0;
+        output.write_str(if negative { "-P" } else { "P" })?;
+
+        let sign = |value: i32| match value.is_negative() && !negative {
+            true => "-",
+            false => "",
+        };
+
+        let (years, months) = (months / 12, months % 12);
+        if years != 0 {
+            write!(output, "{}{}Y", sign(years), years.unsigned_abs())?;
+        }
+
+        if months != 0 {
+            write!(output, "{}{}M", sign(months), months.unsigned_abs())?;
+        }
+
+        if days != 0 {
+            write!(output, "{}{}D", sign(days), days.unsigned_abs())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ext::NumericCalendarDuration;
+    use crate::CalendarDuration;
+
+    #[test]
+    fn format_extremes() {
+        let d0 = CalendarDuration::MAX;
+        assert_eq!(d0.format_iso8601(), "P178956970Y7M2147483647D");
+        let d0 = CalendarDuration::MIN;
+        assert_eq!(d0.format_iso8601(), "-P178956970Y8M2147483648D");
+    }
+
+    #[test]
+    fn format_mixed() {
+        let d0 = 1.days() - 13.months();
+        assert_eq!(d0.format_iso8601(), "P-1Y-1M1D");
+    }
+
+    #[test]
+    #[cfg(feature = "parsing")]
+    fn round_trip() {
+        let durations = [
+            0.months(),
+            14.months(),
+            (-3).months(),
+            1.months() + 10.days(),
+            1.months() - 1.days(),
+            1.days() - 13.months(),
+            CalendarDuration::MAX,
+            CalendarDuration::MIN,
+        ];
+
+        for d0 in durations {
+            let d1 = CalendarDuration::parse_iso8601(&d0.format_iso8601());
+            assert_eq!(d1, Ok(d0));
+        }
+    }
+}
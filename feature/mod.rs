@@ -1,3 +1,7 @@
+#[cfg(feature = "formatting")]
+mod formatting;
+#[cfg(feature = "parsing")]
+mod parsing;
 #[cfg(feature = "rand")]
 mod rand;
 #[cfg(feature = "serde")]
@@ -0,0 +1,177 @@
+use crate::{CalendarDuration, ParseDurationError, ParseDurationErrorKind as Kind};
+
+impl CalendarDuration {
+    /// Parses an ISO 8601 duration, e.g. `P1Y6M`, `-P3M` or `P2W3D`.
+    ///
+    /// Designators must appear in the `Y`, `M`, `W`, `D` order. Each value may
+    /// carry its own sign, e.g. `P1M-1D`. Fractional values and non-zero time
+    /// components cannot be represented and are rejected.
+    ///
+    /// ```rust
+    /// # use timext::{CalendarDuration, ParseDurationErrorKind};
+    /// let d0 = CalendarDuration::parse_iso8601("P1Y6M").unwrap();
+    /// assert_eq!(d0, CalendarDuration::months(18));
+    ///
+    /// let d0 = CalendarDuration::parse_iso8601("-P3M").unwrap();
+    /// assert_eq!(d0, CalendarDuration::months(-3));
+    ///
+    /// let e0 = CalendarDuration::parse_iso8601("P1.5Y").unwrap_err();
+    /// assert_eq!(e0.kind(), ParseDurationErrorKind::FractionalValue);
+    /// assert_eq!(e0.offset(), 2);
+    ///
+    /// let e0 = CalendarDuration::parse_iso8601("P1MT2H").unwrap_err();
+    /// assert_eq!(e0.kind(), ParseDurationErrorKind::TimeComponent);
+    /// assert_eq!(e0.offset(), 4);
+    /// ```
+    pub fn parse_iso8601(input: &str) -> Result<Self, ParseDurationError> {
+        let bytes = input.as_bytes();
+        let mut pos = 0;
+
+        let negative = bytes.first() == Some(&b'-');
+        if matches!(bytes.first(), Some(b'-' | b'+')) {
+            pos += 1;
+        }
+
+        match bytes.get(pos) {
+            Some(b'P') => pos += 1,
+            Some(_) => return Err(ParseDurationError::new(Kind::UnexpectedCharacter, pos)),
+            None => return Err(ParseDurationError::new(Kind::UnexpectedEnd, pos)),
+        }
+
+        // Y, M, W, D, then H, M, S after the time designator.
+        let mut values = [0i32; 4];
+        let mut offsets = [0usize; 4];
+        let mut next = 0;
+        let mut time = false;
+        let mut empty = true;
+
+        while pos < bytes.len() {
+            if bytes[pos] == b'T' {
+                if time {
+                    return Err(ParseDurationError::new(Kind::MisplacedDesignator, pos));
+                }
+
+                time = true;
+                next = next.max(4);
+                pos += 1;
+                if pos == bytes.len() {
+                    return Err(ParseDurationError::new(Kind::UnexpectedEnd, pos));
+                }
+
+                continue;
+            }
+
+            let start = pos;
+            if matches!(bytes[pos], b'-' | b'+') {
+                pos += 1;
+            }
+
+            let digits = pos;
+            while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                pos += 1;
+            }
+
+            if pos == digits {
+                return Err(ParseDurationError::new(Kind::UnexpectedCharacter, pos));
+            }
+
+            let index = match (time, bytes.get(pos)) {
+                (_, None) => return Err(ParseDurationError::new(Kind::UnexpectedEnd, pos)),
+                (_, Some(b'.' | b',')) => {
+                    return Err(ParseDurationError::new(Kind::FractionalValue, pos))
+                }
+                (false, Some(b'Y')) => 0,
+                (false, Some(b'M')) => 1,
+                (false, Some(b'W')) => 2,
+                (false, Some(b'D')) => 3,
+                (true, Some(b'H')) => 4,
+                (true, Some(b'M')) => 5,
+                (true, Some(b'S')) => 6,
+                (_, Some(_)) => {
+                    return Err(ParseDurationError::new(Kind::UnexpectedCharacter, pos))
+                }
+            };
+
+            if index < next {
+                return Err(ParseDurationError::new(Kind::MisplacedDesignator, pos));
+            }
+
+            // The leading sign is applied first to accept `i32::MIN` values.
+            let value = input[start..pos]
+                .parse::<i64>()
+                .ok()
+                .map(|x| if negative { -x } else { x })
+                .and_then(|x| i32::try_from(x).ok())
+                .ok_or_else(|| ParseDurationError::new(Kind::Overflow, start))?;
+
+            if index < 4 {
+                values[index] = value;
+                offsets[index] = start;
+            } else if value != 0 {
+                return Err(ParseDurationError::new(Kind::TimeComponent, start));
+            }
+
+            next = index + 1;
+            empty = false;
+            pos += 1;
+        }
+
+        if empty {
+            return Err(ParseDurationError::new(Kind::UnexpectedEnd, pos));
+        }
+
+        let overflow = |offset| ParseDurationError::new(Kind::Overflow, offset);
+        let [years, months, weeks, days] = values.map(i64::from);
+        let months =
+            i32::try_from(years * 12 + months).map_err(|_| overflow(offsets[0].max(offsets[1])))?;
+        let days =
+            i32::try_from(weeks * 7 + days).map_err(|_| overflow(offsets[2].max(offsets[3])))?;
+
+        Ok(Self::new_with_days(0, months, 0, days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ext::NumericCalendarDuration;
+    use crate::{CalendarDuration, ParseDurationErrorKind as Kind};
+
+    fn parse_err(input: &str) -> (Kind, usize) {
+        let error = CalendarDuration::parse_iso8601(input).unwrap_err();
+        (error.kind(), error.offset())
+    }
+
+    #[test]
+    fn parse_valid() {
+        let parse = CalendarDuration::parse_iso8601;
+        assert_eq!(parse("P0M"), Ok(0.months()));
+        assert_eq!(parse("P1Y2M"), Ok(14.months()));
+        assert_eq!(parse("+P1Y"), Ok(12.months()));
+        assert_eq!(parse("-P1Y2M3W4D"), Ok(-(14.months() + 25.days())));
+        assert_eq!(parse("P2W"), Ok(14.days()));
+        assert_eq!(parse("P1MT0H0S"), Ok(1.months()));
+        assert_eq!(parse("P1M-1D"), Ok(1.months() - 1.days()));
+        assert_eq!(parse("-P-1M+1D"), Ok(1.months() - 1.days()));
+        assert_eq!("-P1Y2M".parse(), Ok((-14).months()));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(parse_err(""), (Kind::UnexpectedEnd, 0));
+        assert_eq!(parse_err("P"), (Kind::UnexpectedEnd, 1));
+        assert_eq!(parse_err("-P"), (Kind::UnexpectedEnd, 2));
+        assert_eq!(parse_err("1M"), (Kind::UnexpectedCharacter, 0));
+        assert_eq!(parse_err("P1"), (Kind::UnexpectedEnd, 2));
+        assert_eq!(parse_err("PT"), (Kind::UnexpectedEnd, 2));
+        assert_eq!(parse_err("PM"), (Kind::UnexpectedCharacter, 1));
+        assert_eq!(parse_err("P-M"), (Kind::UnexpectedCharacter, 2));
+        assert_eq!(parse_err("P1X"), (Kind::UnexpectedCharacter, 2));
+        assert_eq!(parse_err("P1M2Y"), (Kind::MisplacedDesignator, 4));
+        assert_eq!(parse_err("P1M2M"), (Kind::MisplacedDesignator, 4));
+        assert_eq!(parse_err("P1,5M"), (Kind::FractionalValue, 2));
+        assert_eq!(parse_err("PT1S"), (Kind::TimeComponent, 2));
+        assert_eq!(parse_err("P1YT1D"), (Kind::UnexpectedCharacter, 5));
+        assert_eq!(parse_err("P9999999999M"), (Kind::Overflow, 1));
+        assert_eq!(parse_err("P2147483647Y"), (Kind::Overflow, 1));
+    }
+}
@@ -5,8 +5,9 @@ use crate::CalendarDuration;
 
 impl Distribution<CalendarDuration> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CalendarDuration {
-        let min = CalendarDuration::MIN.whole_months();
-        let max = CalendarDuration::MAX.whole_months();
-        CalendarDuration::months(rng.gen_range(min..=max))
+        let (min, max) = (CalendarDuration::MIN, CalendarDuration::MAX);
+        let months = rng.gen_range(min.whole_months()..=max.whole_months());
+        let days = rng.gen_range(min.whole_days()..=max.whole_days());
+        CalendarDuration::new_with_days(0, months, 0, days)
     }
 }
@@ -0,0 +1,184 @@
+use std::fmt::{Formatter, Result as FmtResult};
+
+use serde::de::{Error, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use time::Weekday;
+
+use super::value::{deserialize_fields, DateValue, Fields, List, Text, WeekdayValue};
+use crate::{BusinessCalendar, WeekendMask};
+
+impl Serialize for WeekendMask {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.weekend_days() as usize))?;
+        let mut weekday = Weekday::Monday;
+        for _ in 0..7 {
+            if self.contains(weekday) {
+                seq.serialize_element(&WeekdayValue(weekday))?;
+            }
+
+            weekday = weekday.next();
+        }
+
+        seq.end()
+    }
+}
+
+struct WeekendMaskVisitor;
+
+impl<'de> Visitor<'de> for WeekendMaskVisitor {
+    type Value = WeekendMask;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a list of weekday names")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut weekdays = Vec::new();
+        while let Some(WeekdayValue(weekday)) = seq.next_element()? {
+            weekdays.push(weekday);
+        }
+
+        Ok(WeekendMask::new(&weekdays))
+    }
+}
+
+impl<'de> Deserialize<'de> for WeekendMask {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(WeekendMaskVisitor)
+    }
+}
+
+const FIELDS: &[&str] = &["weekend", "holidays", "half_days"];
+
+impl Serialize for BusinessCalendar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let dates = |x: &[_]| List(x.iter().copied().map(DateValue).collect());
+        let mut map = Fields::new(serializer, "BusinessCalendar", 3)?;
+        map.serialize_field("weekend", &self.weekend())?;
+        map.serialize_field("holidays", &dates(self.holidays()))?;
+        map.serialize_field("half_days", &dates(self.half_days()))?;
+        map.end()
+    }
+}
+
+struct BusinessCalendarVisitor;
+
+impl<'de> Visitor<'de> for BusinessCalendarVisitor {
+    type Value = BusinessCalendar;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a `BusinessCalendar`")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut weekend = None;
+        let mut holidays = None;
+        let mut half_days = None;
+
+        while let Some(Text(key)) = map.next_key()? {
+            match key.as_str() {
+                "weekend" if weekend.is_some() => return Err(Error::duplicate_field("weekend")),
+                "weekend" => weekend = Some(map.next_value()?),
+                "holidays" if holidays.is_some() => return Err(Error::duplicate_field("holidays")),
+                "holidays" => holidays = Some(map.next_value::<List<DateValue>>()?),
+                "half_days" if half_days.is_some() => {
+                    return Err(Error::duplicate_field("half_days"))
+                }
+                "half_days" => half_days = Some(map.next_value::<List<DateValue>>()?),
+                _ => return Err(Error::unknown_field(&key, FIELDS)),
+            }
+        }
+
+        let dates = |x: Option<List<DateValue>>| x.into_iter().flat_map(|x| x.0).map(|x| x.0);
+        let calendar = BusinessCalendar::new(weekend.unwrap_or_default())
+            .with_holidays(dates(holidays))
+            .with_half_days(dates(half_days));
+        Ok(calendar)
+    }
+}
+
+impl<'de> Deserialize<'de> for BusinessCalendar {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_fields(
+            deserializer,
+            "BusinessCalendar",
+            FIELDS,
+            BusinessCalendarVisitor,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::{Date, Weekday::*};
+
+    use crate::{BusinessCalendar, WeekendMask};
+
+    #[test]
+    fn calendar() {
+        let source = r#"
+            weekend = ["friday", "Sat"]
+            holidays = [2024-12-25, "2024-12-26"]
+            half_days = ["2024-12-24"]
+        "#;
+
+        let date = |d| Date::from_calendar_date(2024, December, d).unwrap();
+        let calendar: BusinessCalendar = toml::from_str(source).unwrap();
+        let expected = BusinessCalendar::new(WeekendMask::new(&[Friday, Saturday]))
+            .with_holidays([date(25), date(26)])
+            .with_half_day(date(24));
+        assert_eq!(calendar, expected);
+
+        let json = serde_json::to_string(&calendar).unwrap();
+        let weekend = r#"{"weekend":["friday","saturday"],"#;
+        let dates = r#""holidays":["2024-12-25","2024-12-26"],"half_days":["2024-12-24"]}"#;
+        assert_eq!(json, format!("{}{}", weekend, dates));
+        let calendar: BusinessCalendar = serde_json::from_str(&json).unwrap();
+        assert_eq!(calendar, expected);
+        let bytes = bincode::serialize(&calendar).unwrap();
+        let calendar: BusinessCalendar = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(calendar, expected);
+
+        let calendar: BusinessCalendar = serde_json::from_str("{}").unwrap();
+        assert_eq!(calendar, BusinessCalendar::default());
+    }
+
+    #[test]
+    fn calendar_invalid() {
+        let error = |x| {
+            serde_json::from_str::<BusinessCalendar>(x)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(error(r#"{"weekend":["caturday"]}"#)
+            .starts_with("invalid value: string \"caturday\", expected a weekday name"));
+        assert!(error(r#"{"holidays":["2023-02-29"]}"#).starts_with(
+            "invalid value: string \"2023-02-29\", expected a date in the `YYYY-MM-DD` format"
+        ));
+        assert!(error(r#"{"holidays":[{"date":"2024-12-25"}]}"#)
+            .starts_with("invalid type: map, expected a date in the `YYYY-MM-DD` format"));
+        assert!(error(r#"{"holiday":[]}"#).starts_with(
+            "unknown field `holiday`, expected one of `weekend`, `holidays`, `half_days`"
+        ));
+    }
+}
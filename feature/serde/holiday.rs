@@ -0,0 +1,581 @@
+use std::fmt::{Formatter, Result as FmtResult};
+
+use serde::de::{Error, MapAccess, Unexpected, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use time::Month;
+
+use super::value::{deserialize_fields, Fields, List, MonthValue, Text, WeekdayValue};
+use crate::util::days_in_year_month;
+use crate::{Easter, HolidayDate, HolidayRule, HolidaySet, Observance};
+
+const KINDS: &[&str] = &["fixed", "nth_weekday", "on_or_after", "easter"];
+const EASTERS: &[&str] = &["western", "orthodox"];
+const OBSERVANCES: &[&str] = &[
+    "actual",
+    "nearest_weekday",
+    "next_weekday",
+    "sunday_to_next_day",
+];
+
+const DATE_FIELDS: &[&str] = &["type", "month", "day", "weekday", "n", "easter", "offset"];
+const RULE_FIELDS: &[&str] = &[
+    "name",
+    "type",
+    "month",
+    "day",
+    "weekday",
+    "n",
+    "easter",
+    "offset",
+    "observance",
+    "start_year",
+    "end_year",
+];
+const SET_FIELDS: &[&str] = &["rules"];
+
+/// Defines the string form of a unit enum.
+macro_rules! impl_variants {
+    ($ty:ident, $visitor:ident, $expecting:literal, $names:ident, [$($variant:ident),+]) => {
+        impl Serialize for $ty {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let variants = [$($ty::$variant),+];
+                let index = variants.iter().position(|x| x == self).unwrap_or_default();
+                serializer.serialize_str($names[index])
+            }
+        }
+
+        struct $visitor;
+
+        impl<'de> Visitor<'de> for $visitor {
+            type Value = $ty;
+
+            fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+                formatter.write_str($expecting)
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+                let variants = [$($ty::$variant),+];
+                match $names.iter().position(|x| *x == v) {
+                    Some(index) => Ok(variants[index]),
+                    None => Err(E::unknown_variant(v, $names)),
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_str($visitor)
+            }
+        }
+    };
+}
+
+impl_variants!(
+    Easter,
+    EasterVisitor,
+    "an `Easter`",
+    EASTERS,
+    [Western, Orthodox]
+);
+
+impl_variants!(
+    Observance,
+    ObservanceVisitor,
+    "an `Observance`",
+    OBSERVANCES,
+    [Actual, NearestWeekday, NextWeekday, SundayToNextDay]
+);
+
+/// Returns the number of fields [`serialize_date`] writes.
+fn date_len(date: HolidayDate) -> usize {
+    match date {
+        HolidayDate::Fixed { .. } | HolidayDate::Easter { .. } => 3,
+        HolidayDate::NthWeekday { .. } | HolidayDate::OnOrAfter { .. } => 4,
+    }
+}
+
+/// Serializes the fields of the `HolidayDate` into the struct.
+fn serialize_date<S: SerializeStruct>(date: HolidayDate, map: &mut S) -> Result<(), S::Error> {
+    match date {
+        HolidayDate::Fixed { month, day } => {
+            map.serialize_field("type", KINDS[0])?;
+            map.serialize_field("month", &MonthValue(month))?;
+            map.serialize_field("day", &day)?;
+        }
+        HolidayDate::NthWeekday { month, weekday, n } => {
+            map.serialize_field("type", KINDS[1])?;
+            map.serialize_field("month", &MonthValue(month))?;
+            map.serialize_field("weekday", &WeekdayValue(weekday))?;
+            map.serialize_field("n", &n)?;
+        }
+        HolidayDate::OnOrAfter {
+            month,
+            day,
+            weekday,
+        } => {
+            map.serialize_field("type", KINDS[2])?;
+            map.serialize_field("month", &MonthValue(month))?;
+            map.serialize_field("day", &day)?;
+            map.serialize_field("weekday", &WeekdayValue(weekday))?;
+        }
+        HolidayDate::Easter { easter, offset } => {
+            map.serialize_field("type", KINDS[3])?;
+            map.serialize_field("easter", &easter)?;
+            map.serialize_field("offset", &offset)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Stores the value of the next entry, unless the field was already set.
+fn next_value<'de, T, A>(
+    map: &mut A,
+    slot: &mut Option<T>,
+    field: &'static str,
+) -> Result<(), A::Error>
+where
+    T: Deserialize<'de>,
+    A: MapAccess<'de>,
+{
+    match slot {
+        Some(_) => Err(A::Error::duplicate_field(field)),
+        None => {
+            *slot = Some(map.next_value()?);
+            Ok(())
+        }
+    }
+}
+
+/// The fields of a `HolidayDate`, validated once all of them are read.
+#[derive(Default)]
+struct DateFields {
+    kind: Option<Text>,
+    month: Option<MonthValue>,
+    day: Option<u8>,
+    weekday: Option<WeekdayValue>,
+    n: Option<i8>,
+    easter: Option<Easter>,
+    offset: Option<i16>,
+}
+
+impl DateFields {
+    /// Reads the value of the field, or returns `false` if it is not a field
+    /// of a `HolidayDate`.
+    fn next_value<'de, A>(&mut self, map: &mut A, field: &str) -> Result<bool, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        match field {
+            "type" => next_value(map, &mut self.kind, "type")?,
+            "month" => next_value(map, &mut self.month, "month")?,
+            "day" => next_value(map, &mut self.day, "day")?,
+            "weekday" => next_value(map, &mut self.weekday, "weekday")?,
+            "n" => next_value(map, &mut self.n, "n")?,
+            "easter" => next_value(map, &mut self.easter, "easter")?,
+            "offset" => next_value(map, &mut self.offset, "offset")?,
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    fn build<E: Error>(self) -> Result<HolidayDate, E> {
+        let Text(kind) = self.kind.ok_or_else(|| E::missing_field("type"))?;
+        let allowed: &[&str] = match kind.as_str() {
+            "fixed" => &["month", "day"],
+            "nth_weekday" => &["month", "weekday", "n"],
+            "on_or_after" => &["month", "day", "weekday"],
+            "easter" => &["easter", "offset"],
+            _ => return Err(E::unknown_variant(&kind, KINDS)),
+        };
+
+        let fields = [
+            ("month", self.month.is_some()),
+            ("day", self.day.is_some()),
+            ("weekday", self.weekday.is_some()),
+            ("n", self.n.is_some()),
+            ("easter", self.easter.is_some()),
+            ("offset", self.offset.is_some()),
+        ];
+
+        if let Some((field, _)) = fields.iter().find(|(x, set)| *set && !allowed.contains(x)) {
+            let message = format!("field `{}` is not allowed in a `{}` rule", field, kind);
+            return Err(E::custom(message));
+        }
+
+        let month = self.month.map(|x| x.0);
+        let month = || month.ok_or_else(|| E::missing_field("month"));
+        let weekday = self.weekday.map(|x| x.0);
+        let weekday = || weekday.ok_or_else(|| E::missing_field("weekday"));
+        let day = |month: Month| match self.day {
+            // The year 2000 is a leap year, so February 29 is allowed.
+            Some(day) if (1..=days_in_year_month(2000, month)).contains(&day) => Ok(day),
+            Some(day) => {
+                let expected = format!("a day of {}", month);
+                let unexpected = Unexpected::Unsigned(day as u64);
+                Err(E::invalid_value(unexpected, &expected.as_str()))
+            }
+            None => Err(E::missing_field("day")),
+        };
+
+        let date = match kind.as_str() {
+            "fixed" => {
+                let month = month()?;
+                let day = day(month)?;
+                HolidayDate::Fixed { month, day }
+            }
+            "nth_weekday" => {
+                let n = match self.n {
+                    Some(n @ (-5..=-1 | 1..=5)) => n,
+                    Some(n) => {
+                        let expected = &"a non-zero number from -5 to 5";
+                        return Err(E::invalid_value(Unexpected::Signed(n as i64), expected));
+                    }
+                    None => return Err(E::missing_field("n")),
+                };
+
+                let month = month()?;
+                let weekday = weekday()?;
+                HolidayDate::NthWeekday { month, weekday, n }
+            }
+            "on_or_after" => {
+                let month = month()?;
+                let day = day(month)?;
+                let weekday = weekday()?;
+                HolidayDate::OnOrAfter {
+                    month,
+                    day,
+                    weekday,
+                }
+            }
+            _ => HolidayDate::Easter {
+                easter: self.easter.unwrap_or_default(),
+                offset: self.offset.ok_or_else(|| E::missing_field("offset"))?,
+            },
+        };
+
+        Ok(date)
+    }
+}
+
+impl Serialize for HolidayDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = Fields::new(serializer, "HolidayDate", date_len(*self))?;
+        serialize_date(*self, &mut map)?;
+        map.end()
+    }
+}
+
+struct HolidayDateVisitor;
+
+impl<'de> Visitor<'de> for HolidayDateVisitor {
+    type Value = HolidayDate;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a `HolidayDate`")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut date = DateFields::default();
+        while let Some(Text(key)) = map.next_key()? {
+            if !date.next_value(&mut map, &key)? {
+                return Err(Error::unknown_field(&key, DATE_FIELDS));
+            }
+        }
+
+        date.build()
+    }
+}
+
+impl<'de> Deserialize<'de> for HolidayDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_fields(deserializer, "HolidayDate", DATE_FIELDS, HolidayDateVisitor)
+    }
+}
+
+impl Serialize for HolidayRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let years = self.start_year().is_some() as usize + self.end_year().is_some() as usize;
+        let len = date_len(self.date()) + years + 2;
+        let mut map = Fields::new(serializer, "HolidayRule", len)?;
+        map.serialize_field("name", self.name())?;
+        serialize_date(self.date(), &mut map)?;
+        map.serialize_field("observance", &self.observance())?;
+
+        match self.start_year() {
+            Some(year) => map.serialize_field("start_year", &year)?,
+            None => map.skip_field("start_year")?,
+        }
+
+        match self.end_year() {
+            Some(year) => map.serialize_field("end_year", &year)?,
+            None => map.skip_field("end_year")?,
+        }
+
+        map.end()
+    }
+}
+
+struct HolidayRuleVisitor;
+
+impl<'de> Visitor<'de> for HolidayRuleVisitor {
+    type Value = HolidayRule;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a `HolidayRule`")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut name = None;
+        let mut date = DateFields::default();
+        let mut observance = None;
+        let mut start_year = None;
+        let mut end_year = None;
+
+        while let Some(Text(key)) = map.next_key()? {
+            match key.as_str() {
+                "name" => next_value(&mut map, &mut name, "name")?,
+                "observance" => next_value(&mut map, &mut observance, "observance")?,
+                "start_year" => next_value(&mut map, &mut start_year, "start_year")?,
+                "end_year" => next_value(&mut map, &mut end_year, "end_year")?,
+                _ if date.next_value(&mut map, &key)? => {}
+                _ => return Err(Error::unknown_field(&key, RULE_FIELDS)),
+            }
+        }
+
+        let Text(name) = name.ok_or_else(|| A::Error::missing_field("name"))?;
+        let mut rule = HolidayRule::new(name, date.build()?);
+        rule = rule.with_observance(observance.unwrap_or_default());
+
+        if let (Some(start), Some(end)) = (start_year, end_year) {
+            if start > end {
+                return Err(A::Error::custom("`start_year` is after `end_year`"));
+            }
+        }
+
+        if let Some(year) = start_year {
+            rule = rule.with_start_year(year);
+        }
+
+        if let Some(year) = end_year {
+            rule = rule.with_end_year(year);
+        }
+
+        Ok(rule)
+    }
+}
+
+impl<'de> Deserialize<'de> for HolidayRule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_fields(deserializer, "HolidayRule", RULE_FIELDS, HolidayRuleVisitor)
+    }
+}
+
+impl Serialize for HolidaySet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = Fields::new(serializer, "HolidaySet", 1)?;
+        map.serialize_field("rules", &List(self.rules().iter().collect()))?;
+        map.end()
+    }
+}
+
+struct HolidaySetVisitor;
+
+impl<'de> Visitor<'de> for HolidaySetVisitor {
+    type Value = HolidaySet;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a `HolidaySet`")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut rules = None;
+        while let Some(Text(key)) = map.next_key()? {
+            match key.as_str() {
+                "rules" => next_value::<List<HolidayRule>, _>(&mut map, &mut rules, "rules")?,
+                _ => return Err(Error::unknown_field(&key, SET_FIELDS)),
+            }
+        }
+
+        let rules = rules.map(|x| x.0).unwrap_or_default();
+        Ok(HolidaySet::new().with_rules(rules))
+    }
+}
+
+impl<'de> Deserialize<'de> for HolidaySet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_fields(deserializer, "HolidaySet", SET_FIELDS, HolidaySetVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::Weekday::*;
+
+    use crate::{Easter, HolidayDate, HolidayRule, HolidaySet, Observance};
+
+    #[test]
+    fn rules() {
+        let source = r#"
+            [[rules]]
+            name = "Independence Day"
+            type = "fixed"
+            month = 7
+            day = 4
+            observance = "nearest_weekday"
+
+            [[rules]]
+            name = "Thanksgiving Day"
+            type = "nth_weekday"
+            month = "November"
+            weekday = "thu"
+            n = 4
+
+            [[rules]]
+            name = "Victoria Day"
+            type = "on_or_after"
+            month = 5
+            day = 18
+            weekday = "monday"
+            start_year = 1952
+
+            [[rules]]
+            name = "Orthodox Good Friday"
+            type = "easter"
+            easter = "orthodox"
+            offset = -2
+            end_year = 2030
+        "#;
+
+        let expected = HolidaySet::new()
+            .with_rule(
+                HolidayRule::new(
+                    "Independence Day",
+                    HolidayDate::Fixed {
+                        month: July,
+                        day: 4,
+                    },
+                )
+                .with_observance(Observance::NearestWeekday),
+            )
+            .with_rule(HolidayRule::new(
+                "Thanksgiving Day",
+                HolidayDate::NthWeekday {
+                    month: November,
+                    weekday: Thursday,
+                    n: 4,
+                },
+            ))
+            .with_rule(
+                HolidayRule::new(
+                    "Victoria Day",
+                    HolidayDate::OnOrAfter {
+                        month: May,
+                        day: 18,
+                        weekday: Monday,
+                    },
+                )
+                .with_start_year(1952),
+            )
+            .with_rule(
+                HolidayRule::new(
+                    "Orthodox Good Friday",
+                    HolidayDate::Easter {
+                        easter: Easter::Orthodox,
+                        offset: -2,
+                    },
+                )
+                .with_end_year(2030),
+            );
+
+        let set: HolidaySet = toml::from_str(source).unwrap();
+        assert_eq!(set, expected);
+
+        let set: HolidaySet = toml::from_str(&toml::to_string(&set).unwrap()).unwrap();
+        assert_eq!(set, expected);
+        let set: HolidaySet = serde_json::from_str(&serde_json::to_string(&set).unwrap()).unwrap();
+        assert_eq!(set, expected);
+        let set: HolidaySet = bincode::deserialize(&bincode::serialize(&set).unwrap()).unwrap();
+        assert_eq!(set, expected);
+    }
+
+    #[test]
+    fn rules_invalid() {
+        let error = |x| {
+            serde_json::from_str::<HolidayRule>(x)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(error(r#"{"name":"x","type":"lunar"}"#).starts_with(
+            "unknown variant `lunar`, expected one of `fixed`, `nth_weekday`, `on_or_after`, `easter`"
+        ));
+        assert!(error(r#"{"name":"x","type":"fixed","month":2,"day":30}"#)
+            .starts_with("invalid value: integer `30`, expected a day of February"));
+        assert!(
+            error(r#"{"name":"x","type":"fixed","month":2,"day":29,"n":1}"#)
+                .starts_with("field `n` is not allowed in a `fixed` rule")
+        );
+        assert!(
+            error(r#"{"name":"x","type":"nth_weekday","month":1,"weekday":"mon","n":0}"#)
+                .starts_with("invalid value: integer `0`, expected a non-zero number from -5 to 5")
+        );
+        assert!(
+            error(r#"{"name":"x","type":"nth_weekday","month":1,"n":1}"#)
+                .starts_with("missing field `weekday`")
+        );
+        assert!(error(r#"{"type":"easter"}"#).starts_with("missing field `name`"));
+        assert!(error(r#"{"name":"x","type":"easter"}"#).starts_with("missing field `offset`"));
+        assert!(error(r#"{"name":"x","type":"easter","observed":"actual"}"#)
+            .starts_with("unknown field `observed`, expected one of `name`, `type`"));
+        assert!(
+            error(r#"{"name":"x","type":"easter","observance":"monday"}"#)
+                .starts_with("unknown variant `monday`, expected one of `actual`")
+        );
+        assert!(error(
+            r#"{"name":"x","type":"easter","offset":0,"start_year":2001,"end_year":2000}"#
+        )
+        .starts_with("`start_year` is after `end_year`"));
+
+        let error = toml::from_str::<HolidaySet>("[[rules]]\nname = \"x\"\ntype = \"lunar\"\n");
+        assert!(error
+            .unwrap_err()
+            .to_string()
+            .contains("unknown variant `lunar`"));
+    }
+}
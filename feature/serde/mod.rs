@@ -0,0 +1,95 @@
+use std::fmt::{Formatter, Result as FmtResult};
+
+use serde::de::{Error, SeqAccess, Unexpected, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::CalendarDuration;
+
+mod business;
+mod holiday;
+mod value;
+
+impl Serialize for CalendarDuration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&self.whole_months())?;
+        tuple.serialize_element(&self.whole_days())?;
+        tuple.end()
+    }
+}
+
+struct CalendarDurationVisitor;
+
+impl<'de> Visitor<'de> for CalendarDurationVisitor {
+    type Value = CalendarDuration;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a `CalendarDuration`")
+    }
+
+    // Durations were serialized as a number of months before days were added.
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        let months =
+            i32::try_from(v).map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))?;
+        Ok(CalendarDuration::months(months))
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        let months =
+            i32::try_from(v).map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))?;
+        Ok(CalendarDuration::months(months))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let months = seq
+            .next_element()?
+            .ok_or_else(|| <A::Error as Error>::custom("expected months"))?;
+        // Older versions also accepted a sequence with the months only.
+        let days = seq.next_element()?.unwrap_or_default();
+        Ok(CalendarDuration::new_with_days(0, months, 0, days))
+    }
+}
+
+impl<'de> Deserialize<'de> for CalendarDuration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_any(CalendarDurationVisitor),
+            false => deserializer.deserialize_tuple(2, CalendarDurationVisitor),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ext::NumericCalendarDuration;
+    use crate::CalendarDuration;
+
+    #[test]
+    fn duration() {
+        let duration = 14.months() + 3.days();
+        let json = serde_json::to_string(&duration).unwrap();
+        assert_eq!(json, "[14,3]");
+        let duration: CalendarDuration = serde_json::from_str(&json).unwrap();
+        assert_eq!(duration, 14.months() + 3.days());
+        let bytes = bincode::serialize(&duration).unwrap();
+        let duration: CalendarDuration = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(duration, 14.months() + 3.days());
+
+        let duration: CalendarDuration = serde_json::from_str("14").unwrap();
+        assert_eq!(duration, 14.months());
+        let duration: CalendarDuration = serde_json::from_str("-14").unwrap();
+        assert_eq!(duration, (-14).months());
+        assert!(serde_json::from_str::<CalendarDuration>("[]").is_err());
+        assert!(serde_json::from_str::<CalendarDuration>("4294967296").is_err());
+    }
+}
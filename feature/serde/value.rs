@@ -0,0 +1,319 @@
+//! Values shared by the calendar and holiday definitions.
+
+use std::fmt::{Formatter, Result as FmtResult};
+use std::marker::PhantomData;
+
+use serde::de::{Error, MapAccess, SeqAccess, Unexpected, Visitor};
+use serde::ser::{SerializeMap, SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use time::{Date, Month, Weekday};
+
+/// A string, e.g. the name of a holiday.
+pub(super) struct Text(pub String);
+
+struct TextVisitor;
+
+impl<'de> Visitor<'de> for TextVisitor {
+    type Value = Text;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a string")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Text(v.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Text {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(TextVisitor)
+    }
+}
+
+/// A month, written as a number from 1 to 12 or as an English name.
+pub(super) struct MonthValue(pub Month);
+
+impl Serialize for MonthValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(self.0 as u8)
+    }
+}
+
+struct MonthVisitor;
+
+impl<'de> Visitor<'de> for MonthVisitor {
+    type Value = MonthValue;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a month number from 1 to 12 or a month name")
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        let month = u8::try_from(v).ok().and_then(|x| Month::try_from(x).ok());
+        let month = month.ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))?;
+        Ok(MonthValue(month))
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+        }
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        let month = (1..=12)
+            .filter_map(|x| Month::try_from(x).ok())
+            .find(|x| matches_name(v, &x.to_string()));
+        let month = month.ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))?;
+        Ok(MonthValue(month))
+    }
+}
+
+impl<'de> Deserialize<'de> for MonthValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_any(MonthVisitor),
+            false => deserializer.deserialize_u8(MonthVisitor),
+        }
+    }
+}
+
+/// A day of the week, written as an English name, e.g. `monday` or `Mon`.
+pub(super) struct WeekdayValue(pub Weekday);
+
+impl Serialize for WeekdayValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0.to_string().to_lowercase())
+    }
+}
+
+struct WeekdayVisitor;
+
+impl<'de> Visitor<'de> for WeekdayVisitor {
+    type Value = WeekdayValue;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a weekday name")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        let mut weekday = Weekday::Monday;
+        for _ in 0..7 {
+            if matches_name(v, &weekday.to_string()) {
+                return Ok(WeekdayValue(weekday));
+            }
+
+            weekday = weekday.next();
+        }
+
+        Err(E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+impl<'de> Deserialize<'de> for WeekdayValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(WeekdayVisitor)
+    }
+}
+
+/// Returns `true` if the value is the name or its three-letter abbreviation,
+/// ignoring the case.
+fn matches_name(value: &str, name: &str) -> bool {
+    value.eq_ignore_ascii_case(name) || value.len() == 3 && value.eq_ignore_ascii_case(&name[..3])
+}
+
+/// A date, written as a `YYYY-MM-DD` string or as a TOML local date.
+pub(super) struct DateValue(pub Date);
+
+impl Serialize for DateValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (year, month, day) = self.0.to_calendar_date();
+        let date = format!("{:04}-{:02}-{:02}", year, month as u8, day);
+        serializer.serialize_str(&date)
+    }
+}
+
+/// The key of the map a TOML date or date-time is deserialized as.
+const TOML_DATETIME: &str = "$__toml_private_datetime";
+
+struct DateVisitor;
+
+impl<'de> Visitor<'de> for DateVisitor {
+    type Value = DateValue;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a date in the `YYYY-MM-DD` format")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        let mut parts = v.rsplitn(3, '-');
+        let day = parts.next().and_then(|x| x.parse().ok());
+        let month = parts.next().and_then(|x| x.parse::<u8>().ok());
+        let month = month.and_then(|x| Month::try_from(x).ok());
+        let year = parts.next().and_then(|x| x.parse().ok());
+
+        let date = match (year, month, day) {
+            (Some(year), Some(month), Some(day)) => Date::from_calendar_date(year, month, day).ok(),
+            _ => None,
+        };
+
+        let date = date.ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))?;
+        Ok(DateValue(date))
+    }
+
+    // TOML local dates are deserialized as a map with a single private entry.
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        match map.next_entry::<Text, Text>()? {
+            Some((Text(key), Text(date))) if key == TOML_DATETIME => self.visit_str(&date),
+            _ => Err(A::Error::invalid_type(Unexpected::Map, &self)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DateValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_any(DateVisitor),
+            false => deserializer.deserialize_str(DateVisitor),
+        }
+    }
+}
+
+/// A list of values.
+pub(super) struct List<T>(pub Vec<T>);
+
+impl<T: Serialize> Serialize for List<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for value in &self.0 {
+            seq.serialize_element(value)?;
+        }
+
+        seq.end()
+    }
+}
+
+struct ListVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ListVisitor<T> {
+    type Value = List<T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a list")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+
+        Ok(List(values))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for List<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ListVisitor(PhantomData))
+    }
+}
+
+/// The fields of a struct.
+///
+/// The fields present depend on the value, so formats that are not
+/// self-describing write them as a map with string keys instead.
+pub(super) enum Fields<S: Serializer> {
+    Struct(S::SerializeStruct),
+    Map(S::SerializeMap),
+}
+
+impl<S: Serializer> Fields<S> {
+    /// Starts the struct, where `len` is the number of fields not skipped.
+    pub fn new(serializer: S, name: &'static str, len: usize) -> Result<Self, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.serialize_struct(name, len).map(Self::Struct),
+            false => serializer.serialize_map(Some(len)).map(Self::Map),
+        }
+    }
+}
+
+impl<S: Serializer> SerializeStruct for Fields<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        match self {
+            Self::Struct(x) => x.serialize_field(key, value),
+            Self::Map(x) => x.serialize_entry(key, value),
+        }
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        match self {
+            Self::Struct(x) => x.skip_field(key),
+            Self::Map(_) => Ok(()),
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        match self {
+            Self::Struct(x) => x.end(),
+            Self::Map(x) => x.end(),
+        }
+    }
+}
+
+/// Deserializes the struct written by [`Fields`].
+pub(super) fn deserialize_fields<'de, D, V>(
+    deserializer: D,
+    name: &'static str,
+    fields: &'static [&'static str],
+    visitor: V,
+) -> Result<V::Value, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Visitor<'de>,
+{
+    match deserializer.is_human_readable() {
+        true => deserializer.deserialize_struct(name, fields, visitor),
+        false => deserializer.deserialize_map(visitor),
+    }
+}
@@ -8,6 +8,11 @@
 //!
 //! - `serde` to enable `serde::Serialize` and `serde:Deserialize` impls.
 //! - `rand` to enable `rand::distributions::Distribution` impls.
+//! - `formatting` to enable ISO 8601 duration formatting.
+//! - `parsing` to enable ISO 8601 duration parsing.
+//! - `holidays` to enable all built-in holiday datasets, or `holidays-us`,
+//!   `holidays-nyse`, `holidays-uk`, `holidays-target2`, `holidays-de` and
+//!   `holidays-jp` to enable them one by one.
 //!
 //! ## Examples
 //!
@@ -34,9 +39,22 @@
 mod duration;
 pub use duration::*;
 
+mod calendar;
+pub use calendar::*;
+
+mod partial;
+pub use partial::*;
+
 mod feature;
+// Re-exports the items of the enabled features, none of which are public yet.
+#[allow(unused_imports)]
 pub use feature::*;
 
+mod recurrence;
+pub use recurrence::*;
+
+mod util;
+
 mod extension;
 pub mod ext {
     //! Extension traits.
@@ -0,0 +1,58 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The kind of [`ParsePartialDateError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParsePartialDateErrorKind {
+    /// An input that is not one of `YYYY`, `YYYY-MM`, `--MM-DD` or `YYYY-MM-DD`.
+    InvalidFormat,
+    /// A year that is malformed or out of range.
+    InvalidYear,
+    /// A month that is malformed or not in `01..=12`.
+    InvalidMonth,
+    /// A day that is malformed or does not exist in its month.
+    InvalidDay,
+}
+
+/// An error returned when parsing a [`PartialDate`] fails.
+///
+/// [`PartialDate`]: crate::PartialDate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParsePartialDateError {
+    kind: ParsePartialDateErrorKind,
+    offset: usize,
+}
+
+impl ParsePartialDateError {
+    pub(crate) const fn new(kind: ParsePartialDateErrorKind, offset: usize) -> Self {
+        Self { kind, offset }
+    }
+
+    /// Returns the kind of the error.
+    #[must_use]
+    pub const fn kind(&self) -> ParsePartialDateErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset of the input at which the error occurred.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for ParsePartialDateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let message = match self.kind {
+            ParsePartialDateErrorKind::InvalidFormat => "invalid format",
+            ParsePartialDateErrorKind::InvalidYear => "invalid year",
+            ParsePartialDateErrorKind::InvalidMonth => "invalid month",
+            ParsePartialDateErrorKind::InvalidDay => "invalid day",
+        };
+
+        write!(f, "{} at byte {}", message, self.offset)
+    }
+}
+
+impl Error for ParsePartialDateError {}
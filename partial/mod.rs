@@ -0,0 +1,497 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+use time::{Date, Month};
+
+use crate::util::days_in_year_month;
+
+mod error;
+pub use error::*;
+
+/// A date with some of its components unknown: a year, a year and a month,
+/// a month and a day, e.g. a birthday without a year, or a complete date.
+///
+/// Parses from and formats to the ISO 8601 forms `YYYY`, `YYYY-MM`,
+/// `--MM-DD` and `YYYY-MM-DD`.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::PartialDate;
+/// let d0 = Date::from_calendar_date(2023, May, 1).unwrap();
+/// let d1 = Date::from_calendar_date(2023, May, 31).unwrap();
+/// let date: PartialDate = "2023-05".parse().unwrap();
+/// assert_eq!(date.earliest(), Some(d0));
+/// assert_eq!(date.latest(), Some(d1));
+///
+/// let d2 = Date::from_calendar_date(2024, May, 12).unwrap();
+/// let date: PartialDate = "--05-12".parse().unwrap();
+/// assert_eq!(date.next(d1), Some(d2));
+/// assert_eq!(date.to_string(), "--05-12");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PartialDate {
+    year: Option<i32>,
+    month: Option<Month>,
+    day: Option<u8>,
+}
+
+impl PartialDate {
+    /// Creates a new `PartialDate` with only a year.
+    ///
+    /// Returns `None` if the year is out of the range of [`Date`].
+    #[must_use]
+    pub const fn from_year(year: i32) -> Option<Self> {
+        match year >= Date::MIN.year() && year <= Date::MAX.year() {
+            true => Some(Self {
+                year: Some(year),
+                month: None,
+                day: None,
+            }),
+            false => None,
+        }
+    }
+
+    /// Creates a new `PartialDate` with a year and a month.
+    ///
+    /// Returns `None` if the year is out of the range of [`Date`].
+    #[must_use]
+    pub const fn from_year_month(year: i32, month: Month) -> Option<Self> {
+        match Self::from_year(year) {
+            Some(_) => Some(Self {
+                year: Some(year),
+                month: Some(month),
+                day: None,
+            }),
+            None => None,
+        }
+    }
+
+    /// Creates a new `PartialDate` with a month and a day, but no year.
+    ///
+    /// Returns `None` if the day does not exist in the month of any year, so
+    /// February 29 is allowed.
+    ///
+    /// ```rust
+    /// # use time::Month::*;
+    /// # use timext::PartialDate;
+    /// assert!(PartialDate::from_month_day(February, 29).is_some());
+    /// assert!(PartialDate::from_month_day(April, 31).is_none());
+    /// ```
+    #[must_use]
+    pub const fn from_month_day(month: Month, day: u8) -> Option<Self> {
+        // The year 0 is a leap year.
+        match day >= 1 && day <= days_in_year_month(0, month) {
+            true => Some(Self {
+                year: None,
+                month: Some(month),
+                day: Some(day),
+            }),
+            false => None,
+        }
+    }
+
+    /// Creates a new `PartialDate` with all the components of the date.
+    #[must_use]
+    pub const fn from_date(date: Date) -> Self {
+        Self {
+            year: Some(date.year()),
+            month: Some(date.month()),
+            day: Some(date.day()),
+        }
+    }
+
+    /// Returns the year, if known.
+    #[must_use]
+    pub const fn year(&self) -> Option<i32> {
+        self.year
+    }
+
+    /// Returns the month, if known.
+    #[must_use]
+    pub const fn month(&self) -> Option<Month> {
+        self.month
+    }
+
+    /// Returns the day of the month, if known.
+    #[must_use]
+    pub const fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// Returns `true` if all the components are known.
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        self.year.is_some() && self.month.is_some() && self.day.is_some()
+    }
+
+    /// Returns the date, if all the components are known.
+    #[must_use]
+    pub fn to_date(&self) -> Option<Date> {
+        Date::from_calendar_date(self.year?, self.month?, self.day?).ok()
+    }
+
+    /// Returns the first date matching the partial date, or `None` if the
+    /// year is unknown.
+    #[must_use]
+    pub fn earliest(&self) -> Option<Date> {
+        let month = self.month.unwrap_or(Month::January);
+        Date::from_calendar_date(self.year?, month, self.day.unwrap_or(1)).ok()
+    }
+
+    /// Returns the last date matching the partial date, or `None` if the
+    /// year is unknown.
+    #[must_use]
+    pub fn latest(&self) -> Option<Date> {
+        let year = self.year?;
+        let month = self.month.unwrap_or(Month::December);
+        let day = self.day.unwrap_or(days_in_year_month(year, month));
+        Date::from_calendar_date(year, month, day).ok()
+    }
+
+    /// Returns `true` if the date matches the known components.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::PartialDate;
+    /// let d0 = Date::from_calendar_date(2024, February, 29).unwrap();
+    /// let date = PartialDate::from_month_day(February, 29).unwrap();
+    /// assert!(date.contains(d0));
+    /// assert!(PartialDate::from_year(2024).unwrap().contains(d0));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, date: Date) -> bool {
+        self.year.is_none_or(|x| x == date.year())
+            && self.month.is_none_or(|x| x == date.month())
+            && self.day.is_none_or(|x| x == date.day())
+    }
+
+    /// Returns the first date after the provided one that matches the
+    /// partial date, e.g. the next birthday.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::PartialDate;
+    /// let d0 = Date::from_calendar_date(2097, March, 1).unwrap();
+    /// let d1 = Date::from_calendar_date(2104, February, 29).unwrap();
+    /// let date = PartialDate::from_month_day(February, 29).unwrap();
+    /// assert_eq!(date.next(d0), Some(d1));
+    /// ```
+    #[must_use]
+    pub fn next(&self, date: Date) -> Option<Date> {
+        match self.year {
+            Some(_) => {
+                let next = date.next_day()?.max(self.earliest()?);
+                (next <= self.latest()?).then_some(next)
+            }
+            // February 29 occurs at least once in every 8 years.
+            None => (0..=8)
+                .filter_map(|x| self.with_year(date.year().checked_add(x)?))
+                .find(|x| *x > date),
+        }
+    }
+
+    /// Returns the last date before the provided one that matches the
+    /// partial date.
+    #[must_use]
+    pub fn previous(&self, date: Date) -> Option<Date> {
+        match self.year {
+            Some(_) => {
+                let previous = date.previous_day()?.min(self.latest()?);
+                (previous >= self.earliest()?).then_some(previous)
+            }
+            None => (0..=8)
+                .filter_map(|x| self.with_year(date.year().checked_sub(x)?))
+                .find(|x| *x < date),
+        }
+    }
+
+    /// Returns the date of a month and a day in the provided year.
+    fn with_year(&self, year: i32) -> Option<Date> {
+        Date::from_calendar_date(year, self.month?, self.day?).ok()
+    }
+}
+
+impl PartialOrd for PartialDate {
+    /// Compares the dates matching two partial dates.
+    ///
+    /// A partial date is before another one if all of its dates are before
+    /// all of the other's, and those without a year are compared by their
+    /// month and day. Others, e.g. `2023` and `2023-05`, are not comparable.
+    ///
+    /// ```rust
+    /// # use timext::PartialDate;
+    /// let d0: PartialDate = "2023".parse().unwrap();
+    /// let d1: PartialDate = "2023-05".parse().unwrap();
+    /// let d2: PartialDate = "2024-01-01".parse().unwrap();
+    /// assert!(d0 < d2 && d1 < d2);
+    /// assert_eq!(d0.partial_cmp(&d1), None);
+    /// ```
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+
+        match (self.year, other.year) {
+            (None, None) => {
+                let lhs = (self.month? as u8, self.day?);
+                let rhs = (other.month? as u8, other.day?);
+                Some(lhs.cmp(&rhs))
+            }
+            (Some(_), Some(_)) if self.latest()? < other.earliest()? => Some(Ordering::Less),
+            (Some(_), Some(_)) if self.earliest()? > other.latest()? => Some(Ordering::Greater),
+            _ => None,
+        }
+    }
+}
+
+impl Display for PartialDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.year {
+            Some(year @ 0..=9999) => write!(f, "{:04}", year)?,
+            Some(year) => write!(f, "{:+05}", year)?,
+            None => f.write_str("-")?,
+        }
+
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month as u8)?;
+        }
+
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses exactly two ASCII digits.
+fn two_digits(s: &str) -> Option<u8> {
+    match s.as_bytes() {
+        [x @ b'0'..=b'9', y @ b'0'..=b'9'] => Some((x - b'0') * 10 + (y - b'0')),
+        _ => None,
+    }
+}
+
+impl FromStr for PartialDate {
+    type Err = ParsePartialDateError;
+
+    /// Parses one of `YYYY`, `YYYY-MM`, `--MM-DD` or `YYYY-MM-DD`.
+    ///
+    /// A year before the year 0 is prefixed with a minus sign, e.g. `-0044`.
+    ///
+    /// ```rust
+    /// # use time::Month::*;
+    /// # use timext::{ParsePartialDateErrorKind, PartialDate};
+    /// let date: PartialDate = "--02-29".parse().unwrap();
+    /// assert_eq!(date, PartialDate::from_month_day(February, 29).unwrap());
+    ///
+    /// let error = "2023-02-29".parse::<PartialDate>().unwrap_err();
+    /// assert_eq!(error.kind(), ParsePartialDateErrorKind::InvalidDay);
+    /// assert_eq!(error.offset(), 8);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use ParsePartialDateErrorKind::*;
+
+        let (year, offset) = match s.strip_prefix("--") {
+            Some(_) => (None, 2),
+            None => {
+                let sign = usize::from(s.starts_with(['+', '-']));
+                let end = s[sign..].find('-').map_or(s.len(), |x| x + sign);
+                let digits = &s[sign..end];
+
+                let error = ParsePartialDateError::new(InvalidYear, 0);
+                if digits.len() < 4 || (sign == 0 && digits.len() > 4) {
+                    return Err(error);
+                }
+
+                if !digits.bytes().all(|x| x.is_ascii_digit()) {
+                    return Err(error);
+                }
+
+                let year = s[..end].parse().map_err(|_| error)?;
+                let date = Self::from_year(year).ok_or(error)?;
+                if end == s.len() {
+                    return Ok(date);
+                }
+
+                (Some(year), end + 1)
+            }
+        };
+
+        let rest = &s[offset..];
+        let month = rest.get(..2).and_then(two_digits);
+        let month = month.and_then(|x| Month::try_from(x).ok());
+        let month = month.ok_or(ParsePartialDateError::new(InvalidMonth, offset))?;
+
+        let error = ParsePartialDateError::new(InvalidDay, offset + 3);
+        let day = match rest[2..].strip_prefix('-') {
+            Some(day) => Some(two_digits(day).ok_or(error)?),
+            None if rest.len() == 2 && year.is_some() => None,
+            None => return Err(ParsePartialDateError::new(InvalidFormat, offset + 2)),
+        };
+
+        let date = match (year, day) {
+            (Some(year), Some(day)) => Date::from_calendar_date(year, month, day)
+                .ok()
+                .map(Self::from_date),
+            (Some(year), None) => Self::from_year_month(year, month),
+            (None, Some(day)) => Self::from_month_day(month, day),
+            (None, None) => None,
+        };
+
+        date.ok_or(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use time::Month::*;
+    use time::{Date, Month};
+
+    use crate::{ParsePartialDateErrorKind, PartialDate};
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    fn partial(s: &str) -> PartialDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let inputs = ["2023", "2023-05", "--05-12", "2023-05-12", "-0044"];
+        for input in inputs {
+            assert_eq!(partial(input).to_string(), input);
+        }
+
+        assert_eq!(partial("2023"), PartialDate::from_year(2023).unwrap());
+        let value = PartialDate::from_year_month(2023, May).unwrap();
+        assert_eq!(partial("2023-05"), value);
+        let value = PartialDate::from_month_day(May, 12).unwrap();
+        assert_eq!(partial("--05-12"), value);
+        let value = PartialDate::from_date(date(2023, May, 12));
+        assert_eq!(partial("2023-05-12"), value);
+        assert!(value.is_complete());
+        assert_eq!(value.to_date(), Some(date(2023, May, 12)));
+
+        use ParsePartialDateErrorKind::*;
+        let errors = [
+            ("", InvalidYear, 0),
+            ("23", InvalidYear, 0),
+            ("+10000", InvalidYear, 0),
+            ("20234", InvalidYear, 0),
+            ("2023x", InvalidYear, 0),
+            ("2023-", InvalidMonth, 5),
+            ("2023-13", InvalidMonth, 5),
+            ("2023-5", InvalidMonth, 5),
+            ("2023-05-", InvalidDay, 8),
+            ("2023-05-32", InvalidDay, 8),
+            ("2023-02-29", InvalidDay, 8),
+            ("2023-05x", InvalidFormat, 7),
+            ("2023-05-120", InvalidDay, 8),
+            ("--05", InvalidFormat, 4),
+            ("--04-31", InvalidDay, 5),
+        ];
+
+        for (input, kind, offset) in errors {
+            let error = input.parse::<PartialDate>().unwrap_err();
+            assert_eq!((error.kind(), error.offset()), (kind, offset), "{}", input);
+        }
+    }
+
+    #[test]
+    fn validation() {
+        assert!(PartialDate::from_month_day(February, 29).is_some());
+        assert!(PartialDate::from_month_day(February, 30).is_none());
+        assert!(PartialDate::from_month_day(June, 0).is_none());
+        assert!(PartialDate::from_year(Date::MAX.year() + 1).is_none());
+        assert!(PartialDate::from_year_month(Date::MIN.year() - 1, May).is_none());
+    }
+
+    #[test]
+    fn range() {
+        let value = partial("2023");
+        assert_eq!(value.earliest(), Some(date(2023, January, 1)));
+        assert_eq!(value.latest(), Some(date(2023, December, 31)));
+
+        let value = partial("2024-02");
+        assert_eq!(value.earliest(), Some(date(2024, February, 1)));
+        assert_eq!(value.latest(), Some(date(2024, February, 29)));
+        assert!(value.contains(date(2024, February, 15)));
+        assert!(!value.contains(date(2023, February, 15)));
+
+        let value = partial("--05-12");
+        assert_eq!(value.earliest(), None);
+        assert_eq!(value.latest(), None);
+        assert_eq!(value.to_date(), None);
+    }
+
+    #[test]
+    fn completion() {
+        let value = partial("--05-12");
+        assert_eq!(value.next(date(2026, May, 11)), Some(date(2026, May, 12)));
+        assert_eq!(value.next(date(2026, May, 12)), Some(date(2027, May, 12)));
+        assert_eq!(
+            value.previous(date(2026, May, 12)),
+            Some(date(2025, May, 12))
+        );
+        assert_eq!(
+            value.previous(date(2026, May, 13)),
+            Some(date(2026, May, 12))
+        );
+
+        let value = partial("--02-29");
+        assert_eq!(
+            value.next(date(2024, February, 29)),
+            Some(date(2028, February, 29))
+        );
+        assert_eq!(
+            value.previous(date(2104, February, 28)),
+            Some(date(2096, February, 29))
+        );
+
+        let value = partial("2024-02");
+        assert_eq!(
+            value.next(date(2023, June, 1)),
+            Some(date(2024, February, 1))
+        );
+        assert_eq!(
+            value.next(date(2024, February, 10)),
+            Some(date(2024, February, 11))
+        );
+        assert_eq!(value.next(date(2024, February, 29)), None);
+        assert_eq!(
+            value.previous(date(2024, June, 1)),
+            Some(date(2024, February, 29))
+        );
+        assert_eq!(value.previous(date(2024, February, 1)), None);
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(partial("2023") < partial("2024-01"));
+        assert!(partial("2023-05") < partial("2023-06-01"));
+        assert!(partial("2023-05-31") < partial("2023-06"));
+        assert!(partial("2024") > partial("2023-12-31"));
+        assert!(partial("--01-15") < partial("--02-01"));
+
+        let cases = [
+            ("2023", "2023-05"),
+            ("2023-05", "2023-05-12"),
+            ("--05-12", "2023"),
+            ("--05-12", "2023-05-12"),
+        ];
+
+        for (lhs, rhs) in cases {
+            assert_eq!(partial(lhs).partial_cmp(&partial(rhs)), None);
+            assert_eq!(partial(rhs).partial_cmp(&partial(lhs)), None);
+        }
+
+        let value = partial("2023-05");
+        assert_eq!(value.partial_cmp(&value), Some(Ordering::Equal));
+    }
+}
@@ -0,0 +1,288 @@
+use std::ops::Sub;
+
+use time::Duration;
+
+use crate::ext::CalendarExtension;
+use crate::{CalendarDuration, CalendarSeries};
+
+/// The unit in which a partial [`BillingPeriod`] is measured.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProrationBasis {
+    /// Whole days, so the current partial day counts as remaining.
+    #[default]
+    Day,
+    /// Whole seconds.
+    Second,
+}
+
+impl ProrationBasis {
+    /// Returns the fraction of the whole length that the part represents.
+    fn fraction(self, part: Duration, whole: Duration) -> f64 {
+        let (part, whole) = match self {
+            Self::Day => (part.whole_days(), whole.whole_days()),
+            Self::Second => (part.whole_seconds(), whole.whole_seconds()),
+        };
+
+        match whole {
+            0 => 0.0,
+            _ => part as f64 / whole as f64,
+        }
+    }
+}
+
+/// A period of a [`BillingCycle`], from its start inclusive to its end exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BillingPeriod<T> {
+    index: u32,
+    start: T,
+    end: T,
+}
+
+impl<T> BillingPeriod<T>
+where
+    T: Copy + Ord + Sub<Output = Duration>,
+{
+    /// Returns the number of periods since the anchor of the cycle.
+    #[must_use]
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the start of the period.
+    #[must_use]
+    pub const fn start(&self) -> T {
+        self.start
+    }
+
+    /// Returns the end of the period, which is the start of the next one.
+    #[must_use]
+    pub const fn end(&self) -> T {
+        self.end
+    }
+
+    /// Returns `true` if the period contains the provided value.
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Returns the fraction of the period that has elapsed at the provided
+    /// value, clamped to the period.
+    #[must_use]
+    pub fn elapsed(&self, at: T, basis: ProrationBasis) -> f64 {
+        let at = at.clamp(self.start, self.end);
+        basis.fraction(at - self.start, self.end - self.start)
+    }
+
+    /// Returns the fraction of the period that remains at the provided
+    /// value, clamped to the period, e.g. to credit the unused part of a plan
+    /// on an upgrade or a cancellation.
+    ///
+    /// This is always the complement of [`BillingPeriod::elapsed`], so the
+    /// two fractions add up to the whole period.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{BillingCycle, ProrationBasis};
+    /// # use timext::ext::NumericCalendarDuration;
+    /// let d0 = Date::from_calendar_date(2024, January, 31).unwrap();
+    /// let d1 = Date::from_calendar_date(2024, March, 16).unwrap();
+    /// let at = d1.with_hms(12, 0, 0).unwrap();
+    /// let period = BillingCycle::new(d0.midnight(), 1.months()).period(at).unwrap();
+    /// assert_eq!(period.elapsed(at, ProrationBasis::Day), 16.0 / 31.0);
+    /// assert_eq!(period.remaining(at, ProrationBasis::Day), 15.0 / 31.0);
+    /// ```
+    #[must_use]
+    pub fn remaining(&self, at: T, basis: ProrationBasis) -> f64 {
+        1.0 - self.elapsed(at, basis)
+    }
+}
+
+/// Billing periods of a fixed length anchored on a signup date or date-time.
+///
+/// The n-th period starts at `anchor + length * n`, as the occurrences of a
+/// [`CalendarSeries`], so an anchor on the 29th to the 31st is clamped in
+/// shorter months without drifting in the later ones.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::BillingCycle;
+/// # use timext::ext::NumericCalendarDuration;
+/// let d0 = Date::from_calendar_date(2024, January, 31).unwrap();
+/// let cycle = BillingCycle::new(d0, 1.months());
+///
+/// let d1 = Date::from_calendar_date(2024, March, 15).unwrap();
+/// let period = cycle.period(d1).unwrap();
+/// assert_eq!(period.index(), 1);
+/// assert_eq!(period.start(), Date::from_calendar_date(2024, February, 29).unwrap());
+/// assert_eq!(period.end(), Date::from_calendar_date(2024, March, 31).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BillingCycle<T> {
+    anchor: T,
+    length: CalendarDuration,
+}
+
+impl<T> BillingCycle<T>
+where
+    T: CalendarExtension + Copy + Ord + Sub<Output = Duration>,
+{
+    /// Creates a new `BillingCycle` with periods of the provided length
+    /// starting at the anchor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length is not positive.
+    #[must_use]
+    pub fn new(anchor: T, length: CalendarDuration) -> Self {
+        assert!(
+            length.is_positive() && length.whole_days() >= 0,
+            "length of `timext::BillingCycle` is not positive"
+        );
+
+        Self { anchor, length }
+    }
+
+    /// Returns the start of the first period.
+    #[must_use]
+    pub const fn anchor(&self) -> T {
+        self.anchor
+    }
+
+    /// Returns the length of a period.
+    #[must_use]
+    pub const fn length(&self) -> CalendarDuration {
+        self.length
+    }
+
+    /// Returns the starts of the periods, from the anchor.
+    #[must_use]
+    pub fn starts(&self) -> CalendarSeries<T> {
+        CalendarSeries::new(self.anchor, self.length)
+    }
+
+    /// Returns the start of the n-th period.
+    fn start(&self, n: u32) -> Option<T> {
+        let n = i32::try_from(n).ok()?;
+        self.anchor
+            .checked_calendar_add(self.length.checked_mul(n)?)
+    }
+
+    /// Returns the index of the period containing the value.
+    fn index(&self, value: T) -> Option<u32> {
+        if value < self.anchor {
+            return None;
+        }
+
+        // Estimates the index from the mean length of a month, then corrects it.
+        let days = self.length.whole_months() as f64 * 30.436875 + self.length.whole_days() as f64;
+        let elapsed = (value - self.anchor).as_seconds_f64() / 86_400.0;
+        let mut n = (elapsed / days.max(1.0)) as u32;
+
+        while n > 0 && self.start(n).is_none_or(|x| x > value) {
+            n -= 1;
+        }
+
+        while self.start(n + 1).is_some_and(|x| x <= value) {
+            n += 1;
+        }
+
+        Some(n)
+    }
+
+    /// Returns the n-th period, counted from the anchor.
+    #[must_use]
+    pub fn nth_period(&self, n: u32) -> Option<BillingPeriod<T>> {
+        let start = self.start(n)?;
+        let end = self.start(n.checked_add(1)?)?;
+        Some(BillingPeriod {
+            index: n,
+            start,
+            end,
+        })
+    }
+
+    /// Returns the period containing the provided value, or `None` if it is
+    /// before the anchor.
+    #[must_use]
+    pub fn period(&self, at: T) -> Option<BillingPeriod<T>> {
+        self.nth_period(self.index(at)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::{Date, Month, Time, UtcOffset};
+
+    use crate::ext::NumericCalendarDuration;
+    use crate::{BillingCycle, ProrationBasis};
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn no_drift() {
+        let cycle = BillingCycle::new(date(2024, January, 31), 1.months());
+        let starts: Vec<_> = cycle.starts().take(5).map(|x| x.day()).collect();
+        assert_eq!(starts, [31, 29, 31, 30, 31]);
+
+        for n in 0..36 {
+            let period = cycle.nth_period(n).unwrap();
+            assert_eq!(cycle.period(period.start()), Some(period));
+            assert_eq!(
+                cycle.period(period.end().previous_day().unwrap()),
+                Some(period)
+            );
+            assert!(period.contains(period.start()));
+            assert!(!period.contains(period.end()));
+        }
+
+        let cycle = BillingCycle::new(date(2024, February, 29), 1.years());
+        let period = cycle.period(date(2028, February, 28)).unwrap();
+        assert_eq!(period.index(), 3);
+        assert_eq!(period.start(), date(2027, February, 28));
+        assert_eq!(period.end(), date(2028, February, 29));
+        assert_eq!(cycle.period(date(2024, February, 28)), None);
+
+        let cycle = BillingCycle::new(date(2024, January, 1), 2.weeks());
+        let period = cycle.period(date(2024, March, 1)).unwrap();
+        assert_eq!(period.start(), date(2024, February, 26));
+    }
+
+    #[test]
+    fn proration() {
+        let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+        let time = Time::from_hms(12, 0, 0).unwrap();
+        let anchor = date(2024, January, 30)
+            .with_time(time)
+            .assume_offset(offset);
+        let cycle = BillingCycle::new(anchor, 1.months());
+
+        // The period from February 29 to March 30, with 30 days.
+        let at = date(2024, March, 6).with_hms(18, 0, 0).unwrap();
+        let at = at.assume_offset(offset);
+        let period = cycle.period(at).unwrap();
+        assert_eq!(period.index(), 1);
+
+        assert_eq!(period.elapsed(at, ProrationBasis::Day), 6.0 / 30.0);
+        assert_eq!(period.remaining(at, ProrationBasis::Day), 24.0 / 30.0);
+        assert_eq!(period.elapsed(at, ProrationBasis::Second), 6.25 / 30.0);
+        assert_eq!(period.remaining(at, ProrationBasis::Second), 23.75 / 30.0);
+
+        for basis in [ProrationBasis::Day, ProrationBasis::Second] {
+            let total = period.elapsed(at, basis) + period.remaining(at, basis);
+            assert_eq!(total, 1.0);
+        }
+
+        assert_eq!(period.remaining(anchor, ProrationBasis::Second), 1.0);
+        assert_eq!(period.elapsed(period.end(), ProrationBasis::Second), 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_length() {
+        let _ = BillingCycle::new(date(2024, January, 1), 0.months());
+    }
+}
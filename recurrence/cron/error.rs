@@ -0,0 +1,58 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The kind of [`ParseCronError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseCronErrorKind {
+    /// An expression without 5, 6 or 7 fields.
+    FieldCount,
+    /// A value or name that is malformed or out of range.
+    InvalidValue,
+    /// A term that is not allowed in its field, e.g. `L` in the hour field.
+    InvalidTerm,
+    /// A macro that is not supported, e.g. `@reboot`.
+    UnknownMacro,
+}
+
+/// An error returned when parsing a [`CronSchedule`] fails.
+///
+/// [`CronSchedule`]: crate::CronSchedule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseCronError {
+    kind: ParseCronErrorKind,
+    offset: usize,
+}
+
+impl ParseCronError {
+    pub(crate) const fn new(kind: ParseCronErrorKind, offset: usize) -> Self {
+        Self { kind, offset }
+    }
+
+    /// Returns the kind of the error.
+    #[must_use]
+    pub const fn kind(&self) -> ParseCronErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset of the input at which the error occurred.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for ParseCronError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let message = match self.kind {
+            ParseCronErrorKind::FieldCount => "expected 5, 6 or 7 fields",
+            ParseCronErrorKind::InvalidValue => "invalid value",
+            ParseCronErrorKind::InvalidTerm => "term is not allowed in this field",
+            ParseCronErrorKind::UnknownMacro => "unknown macro",
+        };
+
+        write!(f, "{} at byte {}", message, self.offset)
+    }
+}
+
+impl Error for ParseCronError {}
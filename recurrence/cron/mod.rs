@@ -0,0 +1,515 @@
+use std::iter::FusedIterator;
+
+use time::{Date, Duration, Month, PrimitiveDateTime, Time, Weekday};
+
+use crate::recurrence::local::LocalDateTime;
+use crate::util::days_in_year_month;
+
+mod error;
+pub use error::*;
+
+mod parse;
+
+/// A field of a [`CronSchedule`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CronField {
+    /// The second, in `0..=59`.
+    Second,
+    /// The minute, in `0..=59`.
+    Minute,
+    /// The hour, in `0..=23`.
+    Hour,
+    /// The day of the month, in `1..=31`.
+    DayOfMonth,
+    /// The month, in `1..=12`.
+    Month,
+    /// The day of the week, in `0..=7` where both 0 and 7 are Sunday.
+    DayOfWeek,
+    /// The year, in `1970..=2099`.
+    Year,
+}
+
+impl CronField {
+    const fn index(self) -> usize {
+        self as usize
+    }
+
+    const fn min(self) -> u16 {
+        match self {
+            Self::DayOfMonth | Self::Month => 1,
+            Self::Year => 1970,
+            _ => 0,
+        }
+    }
+
+    const fn max(self) -> u16 {
+        match self {
+            Self::Second | Self::Minute => 59,
+            Self::Hour => 23,
+            Self::DayOfMonth => 31,
+            Self::Month => 12,
+            Self::DayOfWeek => 7,
+            Self::Year => 2099,
+        }
+    }
+}
+
+/// A comma-separated term of a [`CronField`].
+///
+/// Days of the week are numbered from 0 (Sunday) in every dialect, so the
+/// Quartz `1-7` is represented as `0..=6`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CronTerm {
+    /// Every value, `*` or `*/step`.
+    All { step: u16 },
+    /// No specific value, `?`.
+    Any,
+    /// The values from the start to the end, `5`, `1-5`, `1-5/2` or `5/15`.
+    Range { start: u16, end: u16, step: u16 },
+    /// The last day of the month, or the day `offset` days before it, `L` or `L-3`.
+    LastDay { offset: u8 },
+    /// The last Monday to Friday of the month, `LW`.
+    LastWorkday,
+    /// The Monday to Friday nearest to the day of the same month, `15W`.
+    NearestWorkday(u8),
+    /// The last day of the week in the month, `5L`.
+    LastOf(Weekday),
+    /// The n-th day of the week in the month, `5#3`.
+    NthOf(Weekday, u8),
+}
+
+impl CronTerm {
+    /// Returns `true` for `*` or `?`, as a day field starting with either
+    /// is combined with the other day field by conjunction.
+    const fn is_star(&self) -> bool {
+        matches!(self, Self::All { .. } | Self::Any)
+    }
+
+    fn matches(&self, value: u16, min: u16) -> bool {
+        match *self {
+            Self::All { step } => value
+                .checked_sub(min)
+                .is_some_and(|x| x.is_multiple_of(step)),
+            Self::Any => true,
+            Self::Range { start, end, step } => {
+                (start..=end).contains(&value) && (value - start).is_multiple_of(step)
+            }
+            _ => false,
+        }
+    }
+
+    fn matches_day_of_month(&self, date: Date) -> bool {
+        let (day, length) = (date.day(), days_in_year_month(date.year(), date.month()));
+        let workday = |x: Date| x.weekday().number_days_from_monday() < 5;
+        match *self {
+            Self::LastDay { offset } => day + offset == length,
+            Self::LastWorkday => {
+                workday(date) && (day + 1..=length).all(|x| !workday(date.replace_day(x).unwrap()))
+            }
+            Self::NearestWorkday(target) if target <= length => {
+                let target = date.replace_day(target).unwrap();
+                let nearest = match target.weekday() {
+                    Weekday::Saturday if target.day() == 1 => target + Duration::days(2),
+                    Weekday::Saturday => target - Duration::DAY,
+                    Weekday::Sunday if target.day() == length => target - Duration::days(2),
+                    Weekday::Sunday => target + Duration::DAY,
+                    _ => target,
+                };
+
+                nearest == date
+            }
+            _ => self.matches(day as u16, 1),
+        }
+    }
+
+    fn matches_day_of_week(&self, date: Date) -> bool {
+        let weekday = date.weekday();
+        let (day, length) = (date.day(), days_in_year_month(date.year(), date.month()));
+        match *self {
+            Self::LastOf(x) => x == weekday && day + 7 > length,
+            Self::NthOf(x, n) => x == weekday && (day - 1) / 7 + 1 == n,
+            _ => {
+                let value = weekday.number_days_from_sunday() as u16;
+                self.matches(value, 0) || (value == 0 && self.matches(7, 0))
+            }
+        }
+    }
+}
+
+/// A cron expression, e.g. `30 9 * * MON-FRI` or `0 0 12 ? * 6L`.
+///
+/// Expressions with 5 fields are parsed as Unix cron, and expressions with 6
+/// or 7 fields, starting with seconds and optionally ending with years, as
+/// Quartz cron. The `L`, `W`, `#` and `?` terms and the `@yearly`, `@monthly`,
+/// `@weekly`, `@daily` and `@hourly` macros are accepted in every dialect.
+///
+/// If neither the day of the month nor the day of the week starts with `*` or
+/// `?`, a day matches if either of them matches, and otherwise if both match,
+/// as in Vixie cron.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::CronSchedule;
+/// let schedule: CronSchedule = "0 12 L * *".parse().unwrap();
+/// let d0 = Date::from_calendar_date(2024, January, 31).unwrap();
+/// let d1 = Date::from_calendar_date(2024, February, 29).unwrap();
+/// let t0 = d0.with_hms(12, 0, 0).unwrap();
+/// let t1 = d1.with_hms(12, 0, 0).unwrap();
+/// assert_eq!(schedule.next_after(d0.midnight()), Some(t0));
+/// assert_eq!(schedule.upcoming(t0).next(), Some(t1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CronSchedule {
+    fields: [Vec<CronTerm>; 7],
+}
+
+impl CronSchedule {
+    /// Returns the terms of the field.
+    ///
+    /// ```rust
+    /// # use timext::{CronField, CronSchedule, CronTerm};
+    /// let schedule: CronSchedule = "*/15 * * * *".parse().unwrap();
+    /// let terms = schedule.field(CronField::Minute);
+    /// assert_eq!(terms, &[CronTerm::All { step: 15 }]);
+    /// ```
+    #[must_use]
+    pub fn field(&self, field: CronField) -> &[CronTerm] {
+        &self.fields[field.index()]
+    }
+
+    fn matches(&self, field: CronField, value: u16) -> bool {
+        let min = field.min();
+        self.field(field).iter().any(|x| x.matches(value, min))
+    }
+
+    fn matches_day(&self, date: Date) -> bool {
+        let days = self.field(CronField::DayOfMonth);
+        let weekdays = self.field(CronField::DayOfWeek);
+        let day = || days.iter().any(|x| x.matches_day_of_month(date));
+        let weekday = || weekdays.iter().any(|x| x.matches_day_of_week(date));
+        match days[0].is_star() || weekdays[0].is_star() {
+            true => day() && weekday(),
+            false => day() || weekday(),
+        }
+    }
+
+    /// Returns `false` if the days of the month can never fall into the months.
+    fn is_satisfiable(&self) -> bool {
+        if !self.field(CronField::DayOfWeek)[0].is_star() {
+            return true;
+        }
+
+        let mut min = u16::MAX;
+        for term in self.field(CronField::DayOfMonth) {
+            match *term {
+                CronTerm::Range { start, .. } => min = min.min(start),
+                CronTerm::NearestWorkday(day) => min = min.min(day as u16),
+                _ => return true,
+            }
+        }
+
+        (1..=12u8).any(|month| {
+            let length = days_in_year_month(2000, Month::try_from(month).unwrap());
+            self.matches(CronField::Month, month as u16) && min <= length as u16
+        })
+    }
+
+    /// Returns the first matching value at or after the wall-clock time.
+    fn find_forward(&self, mut datetime: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        if !self.is_satisfiable() {
+            return None;
+        }
+
+        loop {
+            let date = datetime.date();
+            let (hour, minute, second) = datetime.as_hms();
+            let year = u16::try_from(date.year()).ok();
+            datetime = if !year.is_some_and(|x| self.matches(CronField::Year, x)) {
+                let year = (date.year() + 1..=Date::MAX.year())
+                    .find(|&x| u16::try_from(x).is_ok_and(|x| self.matches(CronField::Year, x)))?;
+
+                Date::from_calendar_date(year, Month::January, 1)
+                    .ok()?
+                    .midnight()
+            } else if !self.matches(CronField::Month, date.month() as u16) {
+                let length = days_in_year_month(date.year(), date.month());
+                date.replace_day(length).ok()?.next_day()?.midnight()
+            } else if !self.matches_day(date) {
+                date.next_day()?.midnight()
+            } else if !self.matches(CronField::Hour, hour as u16) {
+                let time = Time::from_hms(hour, 0, 0).ok()?;
+                datetime.replace_time(time).checked_add(Duration::HOUR)?
+            } else if !self.matches(CronField::Minute, minute as u16) {
+                let time = Time::from_hms(hour, minute, 0).ok()?;
+                datetime.replace_time(time).checked_add(Duration::MINUTE)?
+            } else if !self.matches(CronField::Second, second as u16) {
+                datetime.checked_add(Duration::SECOND)?
+            } else {
+                return Some(datetime);
+            };
+        }
+    }
+
+    /// Returns the last matching value at or before the wall-clock time.
+    fn find_backward(&self, mut datetime: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        if !self.is_satisfiable() {
+            return None;
+        }
+
+        let last = Time::from_hms(23, 59, 59).unwrap();
+        loop {
+            let date = datetime.date();
+            let (hour, minute, second) = datetime.as_hms();
+            let year = u16::try_from(date.year()).ok();
+            datetime = if !year.is_some_and(|x| self.matches(CronField::Year, x)) {
+                let year = (Date::MIN.year()..date.year())
+                    .rev()
+                    .find(|&x| u16::try_from(x).is_ok_and(|x| self.matches(CronField::Year, x)))?;
+
+                let date = Date::from_calendar_date(year, Month::December, 31).ok()?;
+                PrimitiveDateTime::new(date, last)
+            } else if !self.matches(CronField::Month, date.month() as u16) {
+                PrimitiveDateTime::new(date.replace_day(1).ok()?.previous_day()?, last)
+            } else if !self.matches_day(date) {
+                PrimitiveDateTime::new(date.previous_day()?, last)
+            } else if !self.matches(CronField::Hour, hour as u16) {
+                let time = Time::from_hms(hour, 0, 0).ok()?;
+                datetime.replace_time(time).checked_sub(Duration::SECOND)?
+            } else if !self.matches(CronField::Minute, minute as u16) {
+                let time = Time::from_hms(hour, minute, 0).ok()?;
+                datetime.replace_time(time).checked_sub(Duration::SECOND)?
+            } else if !self.matches(CronField::Second, second as u16) {
+                datetime.checked_sub(Duration::SECOND)?
+            } else {
+                return Some(datetime);
+            };
+        }
+    }
+
+    /// Returns the first firing time strictly after the provided value.
+    ///
+    /// Returns `None` if the schedule never fires again, e.g. `0 0 30 2 *`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::CronSchedule;
+    /// let schedule: CronSchedule = "0 0 30 2 *".parse().unwrap();
+    /// let d0 = Date::from_calendar_date(2024, January, 1).unwrap();
+    /// assert_eq!(schedule.next_after(d0.midnight()), None);
+    /// ```
+    #[must_use]
+    pub fn next_after<T: LocalDateTime>(&self, after: T) -> Option<T> {
+        let local = after.local();
+        let datetime = local
+            .replace_nanosecond(0)
+            .ok()?
+            .checked_add(Duration::SECOND)?;
+        let datetime = self.find_forward(datetime)?;
+        Some(after.with_local(datetime))
+    }
+
+    /// Returns the last firing time strictly before the provided value.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::CronSchedule;
+    /// let schedule: CronSchedule = "0 0 0 ? * 6#3".parse().unwrap();
+    /// let d0 = Date::from_calendar_date(2024, June, 1).unwrap();
+    /// let d1 = Date::from_calendar_date(2024, May, 17).unwrap();
+    /// assert_eq!(schedule.previous_before(d0.midnight()), Some(d1.midnight()));
+    /// ```
+    #[must_use]
+    pub fn previous_before<T: LocalDateTime>(&self, before: T) -> Option<T> {
+        let local = before.local();
+        let datetime = match local.nanosecond() {
+            0 => local.checked_sub(Duration::SECOND)?,
+            _ => local.replace_nanosecond(0).ok()?,
+        };
+
+        let datetime = self.find_backward(datetime)?;
+        Some(before.with_local(datetime))
+    }
+
+    /// Returns an iterator over the firing times strictly after the provided value.
+    #[must_use]
+    pub fn upcoming<T: LocalDateTime>(&self, after: T) -> CronIter<'_, T> {
+        CronIter {
+            schedule: self,
+            current: Some(after),
+            forward: true,
+        }
+    }
+
+    /// Returns an iterator over the firing times strictly before the provided
+    /// value, in descending order.
+    #[must_use]
+    pub fn preceding<T: LocalDateTime>(&self, before: T) -> CronIter<'_, T> {
+        CronIter {
+            schedule: self,
+            current: Some(before),
+            forward: false,
+        }
+    }
+}
+
+/// An iterator over the firing times of a [`CronSchedule`].
+#[derive(Debug, Clone)]
+pub struct CronIter<'a, T> {
+    schedule: &'a CronSchedule,
+    current: Option<T>,
+    forward: bool,
+}
+
+impl<T: LocalDateTime> Iterator for CronIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current?;
+        self.current = match self.forward {
+            true => self.schedule.next_after(current),
+            false => self.schedule.previous_before(current),
+        };
+
+        self.current
+    }
+}
+
+impl<T: LocalDateTime> FusedIterator for CronIter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::{Date, PrimitiveDateTime, UtcOffset};
+
+    use crate::CronSchedule;
+
+    fn datetime(year: i32, month: time::Month, day: u8, hms: (u8, u8, u8)) -> PrimitiveDateTime {
+        let date = Date::from_calendar_date(year, month, day).unwrap();
+        date.with_hms(hms.0, hms.1, hms.2).unwrap()
+    }
+
+    fn upcoming(expression: &str, after: PrimitiveDateTime, n: usize) -> Vec<PrimitiveDateTime> {
+        let schedule: CronSchedule = expression.parse().unwrap();
+        schedule.upcoming(after).take(n).collect()
+    }
+
+    #[test]
+    fn unix() {
+        let d0 = datetime(2024, January, 1, (10, 0, 0));
+        let dates = upcoming("*/20 9-17 * * MON-FRI", d0, 3);
+        let expected = vec![
+            datetime(2024, January, 1, (10, 20, 0)),
+            datetime(2024, January, 1, (10, 40, 0)),
+            datetime(2024, January, 1, (11, 0, 0)),
+        ];
+        assert_eq!(dates, expected);
+
+        // Friday the 5th of January is excluded by the day of the week.
+        let d1 = datetime(2024, January, 5, (17, 40, 0));
+        let dates = upcoming("*/20 9-17 * * 1-4", d1, 1);
+        assert_eq!(dates, vec![datetime(2024, January, 8, (9, 0, 0))]);
+    }
+
+    #[test]
+    fn day_or_weekday() {
+        // Either the 13th or any Friday.
+        let d0 = datetime(2024, September, 10, (0, 0, 0));
+        let dates = upcoming("0 0 13 * FRI", d0, 3);
+        let days: Vec<_> = dates.iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![13, 20, 27]);
+    }
+
+    #[test]
+    fn day_step() {
+        let d0 = datetime(2023, December, 31, (0, 0, 0));
+        let dates = upcoming("0 0 */2 * *", d0, 3);
+        let days: Vec<_> = dates.iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![1, 3, 5]);
+
+        // Sunday, Tuesday, Thursday and Saturday.
+        let dates = upcoming("0 0 * * */2", d0, 4);
+        let days: Vec<_> = dates.iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![2, 4, 6, 7]);
+
+        let dates = upcoming("0 0 */10,15 * *", d0, 5);
+        let days: Vec<_> = dates.iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![1, 11, 15, 21, 31]);
+
+        // Every other day that is also a Monday.
+        let dates = upcoming("0 0 */2 * MON", d0, 2);
+        let days: Vec<_> = dates.iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![1, 15]);
+    }
+
+    #[test]
+    fn quartz() {
+        let d0 = datetime(2024, January, 1, (0, 0, 0));
+        let dates = upcoming("0 0 10 15W * ?", d0, 3);
+        let days: Vec<_> = dates.iter().map(|x| (x.month(), x.day())).collect();
+        assert_eq!(days, vec![(January, 15), (February, 15), (March, 15)]);
+
+        let dates = upcoming("0 0 10 ? * 6L 2024", d0, 2);
+        let days: Vec<_> = dates.iter().map(|x| (x.month(), x.day())).collect();
+        assert_eq!(days, vec![(January, 26), (February, 23)]);
+
+        let dates = upcoming("0 0 0 LW * ?", d0, 3);
+        let days: Vec<_> = dates.iter().map(|x| (x.month(), x.day())).collect();
+        assert_eq!(days, vec![(January, 31), (February, 29), (March, 29)]);
+
+        let dates = upcoming("0 0 0 L-2 * ?", d0, 2);
+        let days: Vec<_> = dates.iter().map(|x| (x.month(), x.day())).collect();
+        assert_eq!(days, vec![(January, 29), (February, 27)]);
+
+        let d1 = datetime(2024, June, 1, (0, 0, 0));
+        let dates = upcoming("0 0 0 1W * ?", d1, 1);
+        assert_eq!(dates, vec![datetime(2024, June, 3, (0, 0, 0))]);
+    }
+
+    #[test]
+    fn macros() {
+        let d0 = datetime(2024, January, 31, (12, 0, 0));
+        assert_eq!(
+            upcoming("@monthly", d0, 1),
+            vec![datetime(2024, February, 1, (0, 0, 0))]
+        );
+        assert_eq!(
+            upcoming("@hourly", d0, 1),
+            vec![datetime(2024, January, 31, (13, 0, 0))]
+        );
+        assert_eq!(
+            upcoming("@weekly", d0, 1),
+            vec![datetime(2024, February, 4, (0, 0, 0))]
+        );
+    }
+
+    #[test]
+    fn previous() {
+        let schedule: CronSchedule = "30 9 * * MON".parse().unwrap();
+        let d0 = datetime(2024, January, 8, (9, 30, 0));
+        let dates: Vec<_> = schedule.preceding(d0).take(2).collect();
+        let expected = vec![
+            datetime(2024, January, 1, (9, 30, 0)),
+            datetime(2023, December, 25, (9, 30, 0)),
+        ];
+        assert_eq!(dates, expected);
+    }
+
+    #[test]
+    fn impossible() {
+        let d0 = datetime(2024, January, 1, (0, 0, 0));
+        assert!(upcoming("0 0 30 2 *", d0, 1).is_empty());
+        assert!(upcoming("0 0 0 * * ? 2020", d0, 1).is_empty());
+
+        let schedule: CronSchedule = "0 0 0 31 4,6 ?".parse().unwrap();
+        assert_eq!(schedule.previous_before(d0), None);
+    }
+
+    #[test]
+    fn offset() {
+        let offset = UtcOffset::from_hms(9, 0, 0).unwrap();
+        let d0 = datetime(2024, January, 1, (0, 0, 0)).assume_offset(offset);
+        let schedule: CronSchedule = "0 8 * * *".parse().unwrap();
+        let d1 = schedule.next_after(d0).unwrap();
+        assert_eq!((d1.hour(), d1.offset()), (8, offset));
+    }
+}
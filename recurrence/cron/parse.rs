@@ -0,0 +1,302 @@
+use std::str::FromStr;
+
+use time::Weekday;
+
+use crate::{CronField, CronSchedule, CronTerm, ParseCronError, ParseCronErrorKind as Kind};
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+const WEEKDAYS: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+const MACROS: [(&str, &str); 7] = [
+    ("@yearly", "0 0 1 1 *"),
+    ("@annually", "0 0 1 1 *"),
+    ("@monthly", "0 0 1 * *"),
+    ("@weekly", "0 0 * * 0"),
+    ("@daily", "0 0 * * *"),
+    ("@midnight", "0 0 * * *"),
+    ("@hourly", "0 * * * *"),
+];
+
+/// Returns the weekday numbered from 0 (Sunday).
+fn weekday(value: u16) -> Weekday {
+    Weekday::Sunday.nth_next((value % 7) as u8)
+}
+
+struct FieldParser {
+    field: CronField,
+    quartz: bool,
+}
+
+impl FieldParser {
+    /// Parses a value or name, numbering the days of the week from 0 (Sunday).
+    fn value(&self, value: &str) -> Option<u16> {
+        let names: &[&str] = match self.field {
+            CronField::Month => &MONTHS,
+            CronField::DayOfWeek => &WEEKDAYS,
+            _ => &[],
+        };
+
+        if let Some(i) = names.iter().position(|x| x.eq_ignore_ascii_case(value)) {
+            return Some(i as u16 + self.field.min());
+        }
+
+        if value.is_empty() || !value.bytes().all(|x| x.is_ascii_digit()) {
+            return None;
+        }
+
+        let value: u16 = value.parse().ok()?;
+        match (self.field, self.quartz) {
+            (CronField::DayOfWeek, true) if (1..=7).contains(&value) => Some(value - 1),
+            (CronField::DayOfWeek, true) => None,
+            (field, _) if (field.min()..=field.max()).contains(&value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn step(&self, step: Option<&str>) -> Option<u16> {
+        match step {
+            Some(step) if step.bytes().all(|x| x.is_ascii_digit()) => {
+                step.parse().ok().filter(|x| *x != 0)
+            }
+            Some(_) => None,
+            None => Some(1),
+        }
+    }
+
+    /// Parses a special term of the day of the month or week, if any.
+    fn special(&self, term: &str) -> Option<Option<CronTerm>> {
+        let upper = term.to_ascii_uppercase();
+        let special = match self.field {
+            CronField::DayOfMonth if upper == "L" => Some(CronTerm::LastDay { offset: 0 }),
+            CronField::DayOfMonth if upper == "LW" => Some(CronTerm::LastWorkday),
+            CronField::DayOfMonth if upper.starts_with("L-") => {
+                let offset = upper[2..].parse().ok().filter(|x| (1..=30).contains(x));
+                offset.map(|offset| CronTerm::LastDay { offset })
+            }
+            CronField::DayOfMonth if upper.ends_with('W') => {
+                let day = self.value(&upper[..upper.len() - 1]);
+                day.map(|x| CronTerm::NearestWorkday(x as u8))
+            }
+            CronField::DayOfWeek if upper == "L" => Some(CronTerm::Range {
+                start: 6,
+                end: 6,
+                step: 1,
+            }),
+            CronField::DayOfWeek if upper.len() > 1 && upper.ends_with('L') => {
+                let value = self.value(&upper[..upper.len() - 1]);
+                value.map(|x| CronTerm::LastOf(weekday(x)))
+            }
+            CronField::DayOfWeek if upper.contains('#') => {
+                let (value, n) = upper.split_once('#').unwrap();
+                let n = n.parse().ok().filter(|x| (1..=5).contains(x));
+                self.value(value)
+                    .zip(n)
+                    .map(|(x, n)| CronTerm::NthOf(weekday(x), n))
+            }
+            CronField::DayOfMonth | CronField::DayOfWeek => return Some(None),
+            _ => {
+                let digits = upper
+                    .trim_end_matches('W')
+                    .bytes()
+                    .all(|x| x.is_ascii_digit());
+                let special = upper == "L" || upper.starts_with("L-") || upper.contains('#');
+                return match special || (upper.ends_with('W') && digits) {
+                    true => None,
+                    false => Some(None),
+                };
+            }
+        };
+
+        Some(special)
+    }
+
+    fn term(&self, term: &str, offset: usize) -> Result<CronTerm, ParseCronError> {
+        let invalid = ParseCronError::new(Kind::InvalidValue, offset);
+        let days = matches!(self.field, CronField::DayOfMonth | CronField::DayOfWeek);
+        if term == "?" {
+            return match days {
+                true => Ok(CronTerm::Any),
+                false => Err(ParseCronError::new(Kind::InvalidTerm, offset)),
+            };
+        }
+
+        match self.special(term) {
+            Some(Some(term)) => return Ok(term),
+            Some(None) => {}
+            None if days => return Err(invalid),
+            None => return Err(ParseCronError::new(Kind::InvalidTerm, offset)),
+        }
+
+        let (range, step) = match term.split_once('/') {
+            Some((range, step)) => (range, Some(step)),
+            None => (term, None),
+        };
+
+        let step = self.step(step).ok_or(invalid)?;
+        if range == "*" {
+            return Ok(CronTerm::All { step });
+        }
+
+        let (start, end) = match range.split_once('-') {
+            Some((start, end)) => {
+                let start = self.value(start).ok_or(invalid)?;
+                (start, self.value(end).ok_or(invalid)?)
+            }
+            None if step != 1 => {
+                let start = self.value(range).ok_or(invalid)?;
+                let max = match (self.field, self.quartz) {
+                    (CronField::DayOfWeek, true) => 6,
+                    (field, _) => field.max(),
+                };
+
+                (start, max)
+            }
+            None => {
+                let value = self.value(range).ok_or(invalid)?;
+                (value, value)
+            }
+        };
+
+        match start <= end {
+            true => Ok(CronTerm::Range { start, end, step }),
+            false => Err(invalid),
+        }
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = ParseCronError;
+
+    /// Parses a Unix or Quartz cron expression, or a macro.
+    ///
+    /// ```rust
+    /// # use timext::{CronField, CronSchedule, CronTerm, ParseCronErrorKind};
+    /// let schedule: CronSchedule = "0 15 10 ? * MON-FRI".parse().unwrap();
+    /// let terms = schedule.field(CronField::DayOfWeek);
+    /// assert_eq!(terms, &[CronTerm::Range { start: 1, end: 5, step: 1 }]);
+    ///
+    /// let error = "0 0 25 * * *".parse::<CronSchedule>().unwrap_err();
+    /// assert_eq!(error.kind(), ParseCronErrorKind::InvalidValue);
+    /// assert_eq!(error.offset(), 4);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim_start().starts_with('@') {
+            let name = s.trim();
+            let offset = s.len() - s.trim_start().len();
+            return MACROS
+                .iter()
+                .find(|x| x.0.eq_ignore_ascii_case(name))
+                .map(|x| x.1.parse().unwrap())
+                .ok_or(ParseCronError::new(Kind::UnknownMacro, offset));
+        }
+
+        let items: Vec<_> = s.split_whitespace().collect();
+        let fields: &[CronField] = match items.len() {
+            5 => &[
+                CronField::Minute,
+                CronField::Hour,
+                CronField::DayOfMonth,
+                CronField::Month,
+                CronField::DayOfWeek,
+            ],
+            6 | 7 => &[
+                CronField::Second,
+                CronField::Minute,
+                CronField::Hour,
+                CronField::DayOfMonth,
+                CronField::Month,
+                CronField::DayOfWeek,
+                CronField::Year,
+            ][..items.len()],
+            _ => return Err(ParseCronError::new(Kind::FieldCount, s.len())),
+        };
+
+        let mut schedule = Self {
+            fields: Default::default(),
+        };
+
+        schedule.fields[CronField::Second.index()] = vec![CronTerm::Range {
+            start: 0,
+            end: 0,
+            step: 1,
+        }];
+
+        schedule.fields[CronField::Year.index()] = vec![CronTerm::Any];
+        for (&field, item) in fields.iter().zip(items) {
+            let parser = FieldParser {
+                field,
+                quartz: fields.len() > 5,
+            };
+
+            let mut offset = item.as_ptr() as usize - s.as_ptr() as usize;
+            let mut terms = Vec::new();
+            for term in item.split(',') {
+                terms.push(parser.term(term, offset)?);
+                offset += term.len() + 1;
+            }
+
+            schedule.fields[field.index()] = terms;
+        }
+
+        Ok(schedule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Weekday::*;
+
+    use crate::{CronField, CronSchedule, CronTerm, ParseCronErrorKind as Kind};
+
+    #[test]
+    fn ast() {
+        let schedule: CronSchedule = "0 0/5 14,18 L-3 JAN-MAR/2 2#1,FRIL".parse().unwrap();
+        let range = |start, end, step| CronTerm::Range { start, end, step };
+        assert_eq!(schedule.field(CronField::Minute), &[range(0, 59, 5)]);
+        assert_eq!(
+            schedule.field(CronField::Hour),
+            &[range(14, 14, 1), range(18, 18, 1)]
+        );
+        assert_eq!(
+            schedule.field(CronField::DayOfMonth),
+            &[CronTerm::LastDay { offset: 3 }]
+        );
+        assert_eq!(schedule.field(CronField::Month), &[range(1, 3, 2)]);
+        assert_eq!(
+            schedule.field(CronField::DayOfWeek),
+            &[CronTerm::NthOf(Monday, 1), CronTerm::LastOf(Friday)]
+        );
+        assert_eq!(schedule.field(CronField::Year), &[CronTerm::Any]);
+    }
+
+    #[test]
+    fn weekday_numbering() {
+        let unix: CronSchedule = "0 0 * * 0,7".parse().unwrap();
+        let quartz: CronSchedule = "0 0 0 ? * 1".parse().unwrap();
+        let range = |x| CronTerm::Range {
+            start: x,
+            end: x,
+            step: 1,
+        };
+        assert_eq!(unix.field(CronField::DayOfWeek), &[range(0), range(7)]);
+        assert_eq!(quartz.field(CronField::DayOfWeek), &[range(0)]);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let parse = |x: &str| x.parse::<CronSchedule>().unwrap_err();
+        assert_eq!(parse("* * * *").kind(), Kind::FieldCount);
+        assert_eq!(parse("@reboot").kind(), Kind::UnknownMacro);
+        let error = parse("* L * * *");
+        assert_eq!((error.kind(), error.offset()), (Kind::InvalidTerm, 2));
+        let error = parse("0 0 ? * 5-1");
+        assert_eq!((error.kind(), error.offset()), (Kind::InvalidValue, 8));
+        let error = parse("*/0 * * * *");
+        assert_eq!(error.kind(), Kind::InvalidValue);
+        let error = parse("0 0 0 ? * 0");
+        assert_eq!((error.kind(), error.offset()), (Kind::InvalidValue, 10));
+    }
+}
@@ -0,0 +1,61 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The kind of [`ParseEventError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseEventErrorKind {
+    /// A day of the week that is malformed.
+    InvalidWeekday,
+    /// A date that is malformed or out of range.
+    InvalidDate,
+    /// A time that is malformed or out of range.
+    InvalidTime,
+    /// A timezone that is not `UTC` or a fixed offset.
+    InvalidTimezone,
+    /// A token that is repeated or out of order.
+    UnexpectedToken,
+}
+
+/// An error returned when parsing a [`CalendarEvent`] fails.
+///
+/// [`CalendarEvent`]: crate::CalendarEvent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseEventError {
+    kind: ParseEventErrorKind,
+    offset: usize,
+}
+
+impl ParseEventError {
+    pub(crate) const fn new(kind: ParseEventErrorKind, offset: usize) -> Self {
+        Self { kind, offset }
+    }
+
+    /// Returns the kind of the error.
+    #[must_use]
+    pub const fn kind(&self) -> ParseEventErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset of the input at which the error occurred.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for ParseEventError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let message = match self.kind {
+            ParseEventErrorKind::InvalidWeekday => "invalid day of the week",
+            ParseEventErrorKind::InvalidDate => "invalid date",
+            ParseEventErrorKind::InvalidTime => "invalid time",
+            ParseEventErrorKind::InvalidTimezone => "invalid timezone",
+            ParseEventErrorKind::UnexpectedToken => "unexpected token",
+        };
+
+        write!(f, "{} at byte {}", message, self.offset)
+    }
+}
+
+impl Error for ParseEventError {}
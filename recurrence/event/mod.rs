@@ -0,0 +1,353 @@
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+use std::iter::FusedIterator;
+
+use time::{Date, Duration, Month, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::recurrence::local::LocalDateTime;
+use crate::util::days_in_year_month;
+
+mod error;
+pub use error::*;
+
+mod parse;
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// A value, range or repetition of a calendar event component, e.g. `4`,
+/// `4..8`, `4/2` or `4..8/2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Component {
+    start: u16,
+    stop: Option<u16>,
+    repeat: Option<u16>,
+}
+
+impl Component {
+    fn matches(&self, value: u16) -> bool {
+        let stop = match (self.stop, self.repeat) {
+            (Some(stop), _) => stop,
+            (None, Some(_)) => u16::MAX,
+            (None, None) => self.start,
+        };
+
+        let repeat = self.repeat.unwrap_or(1);
+        (self.start..=stop).contains(&value) && (value - self.start).is_multiple_of(repeat)
+    }
+
+    /// Matches the day counted from the end of the month, as `~` does.
+    fn matches_from_end(&self, day: u8, length: u8) -> bool {
+        let length = length as i32 + 1;
+        let (start, stop) = match self.stop {
+            Some(stop) => (length - stop as i32, length - self.start as i32),
+            None if self.repeat.is_some() => (length - self.start as i32, length - 1),
+            None => (length - self.start as i32, length - self.start as i32),
+        };
+
+        let (day, repeat) = (day as i32, self.repeat.unwrap_or(1) as i32);
+        (start..=stop).contains(&day) && (day - start) % repeat == 0
+    }
+}
+
+fn matches(components: &[Component], value: u16) -> bool {
+    components.is_empty() || components.iter().any(|x| x.matches(value))
+}
+
+fn write_components(f: &mut String, width: usize, components: &[Component]) -> FmtResult {
+    if components.is_empty() {
+        return f.write_char('*');
+    }
+
+    for (i, component) in components.iter().enumerate() {
+        if i != 0 {
+            f.write_char(',')?;
+        }
+
+        write!(f, "{:0width$}", component.start, width = width)?;
+        if let Some(stop) = component.stop {
+            write!(f, "..{:0width$}", stop, width = width)?;
+        }
+
+        if let Some(repeat) = component.repeat {
+            write!(f, "/{}", repeat)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// A systemd calendar event, e.g. `Mon..Fri *-*-* 09:00:00` or `*-02~01`.
+///
+/// Events are parsed with [`str::parse`] following `systemd.time(7)`, and
+/// formatted with [`Display`] in the normalized form printed by
+/// `systemd-analyze calendar`. Timezones are limited to `UTC` and fixed
+/// offsets, e.g. `+05:30`.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::CalendarEvent;
+/// let event: CalendarEvent = "quarterly".parse().unwrap();
+/// assert_eq!(event.to_string(), "*-01,04,07,10-01 00:00:00");
+///
+/// let d0 = Date::from_calendar_date(2024, February, 10).unwrap();
+/// let d1 = Date::from_calendar_date(2024, April, 1).unwrap();
+/// assert_eq!(event.next_elapse(d0.midnight()), Some(d1.midnight()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CalendarEvent {
+    weekdays: u8,
+    year: Vec<Component>,
+    month: Vec<Component>,
+    day: Vec<Component>,
+    end_of_month: bool,
+    hour: Vec<Component>,
+    minute: Vec<Component>,
+    second: Vec<Component>,
+    offset: Option<UtcOffset>,
+}
+
+impl CalendarEvent {
+    /// Returns the fixed offset of the event's timezone, if any.
+    #[must_use]
+    pub const fn offset(&self) -> Option<UtcOffset> {
+        self.offset
+    }
+
+    fn matches_day(&self, date: Date) -> bool {
+        let weekday = date.weekday().number_days_from_monday();
+        if self.weekdays != 0 && self.weekdays & (1 << weekday) == 0 {
+            return false;
+        }
+
+        let (day, length) = (date.day(), days_in_year_month(date.year(), date.month()));
+        match self.end_of_month {
+            true => self.day.is_empty() || self.day.iter().any(|x| x.matches_from_end(day, length)),
+            false => matches(&self.day, day as u16),
+        }
+    }
+
+    /// Returns the first matching value at or after the wall-clock time.
+    fn find(&self, mut datetime: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        loop {
+            let date = datetime.date();
+            let (hour, minute, second) = datetime.as_hms();
+            let year = u16::try_from(date.year()).ok();
+            datetime = if !year.is_some_and(|x| matches(&self.year, x)) {
+                let year = (date.year() + 1..=Date::MAX.year())
+                    .find(|&x| u16::try_from(x).is_ok_and(|x| matches(&self.year, x)))?;
+
+                Date::from_calendar_date(year, Month::January, 1)
+                    .ok()?
+                    .midnight()
+            } else if !matches(&self.month, date.month() as u16) {
+                let length = days_in_year_month(date.year(), date.month());
+                date.replace_day(length).ok()?.next_day()?.midnight()
+            } else if !self.matches_day(date) {
+                date.next_day()?.midnight()
+            } else if !matches(&self.hour, hour as u16) {
+                let time = Time::from_hms(hour, 0, 0).ok()?;
+                datetime.replace_time(time).checked_add(Duration::HOUR)?
+            } else if !matches(&self.minute, minute as u16) {
+                let time = Time::from_hms(hour, minute, 0).ok()?;
+                datetime.replace_time(time).checked_add(Duration::MINUTE)?
+            } else if !matches(&self.second, second as u16) {
+                datetime.checked_add(Duration::SECOND)?
+            } else {
+                return Some(datetime);
+            };
+        }
+    }
+
+    /// Returns the first elapse strictly after the provided value.
+    ///
+    /// Values with an offset are converted into the event's timezone, and the
+    /// result is returned in the offset of the provided value. Values without
+    /// an offset are assumed to be in the event's timezone.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*, UtcOffset};
+    /// # use timext::CalendarEvent;
+    /// let event: CalendarEvent = "*-*-* 09:00 UTC".parse().unwrap();
+    /// let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+    /// let d0 = Date::from_calendar_date(2024, January, 1).unwrap();
+    /// let t0 = d0.midnight().assume_offset(offset);
+    /// let t1 = d0.with_hms(11, 0, 0).unwrap().assume_offset(offset);
+    /// assert_eq!(event.next_elapse(t0), Some(t1));
+    /// ```
+    #[must_use]
+    pub fn next_elapse<T: LocalDateTime>(&self, after: T) -> Option<T> {
+        let local = after.local();
+        let local = match (self.offset, after.local_offset()) {
+            (Some(zone), Some(offset)) => {
+                let datetime = local.assume_offset(offset).checked_to_offset(zone)?;
+                PrimitiveDateTime::new(datetime.date(), datetime.time())
+            }
+            _ => local,
+        };
+
+        let datetime = local
+            .replace_nanosecond(0)
+            .ok()?
+            .checked_add(Duration::SECOND)?;
+        let datetime = self.find(datetime)?;
+        let datetime = match (self.offset, after.local_offset()) {
+            (Some(zone), Some(offset)) => {
+                let datetime = datetime.assume_offset(zone).checked_to_offset(offset)?;
+                PrimitiveDateTime::new(datetime.date(), datetime.time())
+            }
+            _ => datetime,
+        };
+
+        Some(after.with_local(datetime))
+    }
+
+    /// Returns an iterator over the elapses strictly after the provided value.
+    #[must_use]
+    pub fn upcoming<T: LocalDateTime>(&self, after: T) -> CalendarEventIter<'_, T> {
+        CalendarEventIter {
+            event: self,
+            current: Some(after),
+        }
+    }
+}
+
+impl Display for CalendarEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut output = String::new();
+        if self.weekdays != 0 {
+            // Runs of three or more days are written as ranges.
+            let mut i = 0;
+            while i < 7 {
+                if self.weekdays & (1 << i) == 0 {
+                    i += 1;
+                    continue;
+                }
+
+                let mut end = i;
+                while end < 6 && self.weekdays & (1 << (end + 1)) != 0 {
+                    end += 1;
+                }
+
+                if !output.is_empty() {
+                    output.push(',');
+                }
+
+                match end - i {
+                    0 => output.push_str(WEEKDAYS[i]),
+                    1 => write!(output, "{},{}", WEEKDAYS[i], WEEKDAYS[end])?,
+                    _ => write!(output, "{}..{}", WEEKDAYS[i], WEEKDAYS[end])?,
+                }
+
+                i = end + 1;
+            }
+
+            output.push(' ');
+        }
+
+        write_components(&mut output, 4, &self.year)?;
+        output.push('-');
+        write_components(&mut output, 2, &self.month)?;
+        output.push(if self.end_of_month { '~' } else { '-' });
+        write_components(&mut output, 2, &self.day)?;
+        output.push(' ');
+        write_components(&mut output, 2, &self.hour)?;
+        output.push(':');
+        write_components(&mut output, 2, &self.minute)?;
+        output.push(':');
+        write_components(&mut output, 2, &self.second)?;
+
+        match self.offset {
+            Some(offset) if offset.is_utc() => output.push_str(" UTC"),
+            Some(offset) => {
+                let (hours, minutes, _) = offset.as_hms();
+                let sign = if offset.is_negative() { '-' } else { '+' };
+                let (hours, minutes) = (hours.unsigned_abs(), minutes.unsigned_abs());
+                write!(output, " {}{:02}:{:02}", sign, hours, minutes)?;
+            }
+            None => {}
+        }
+
+        f.pad(&output)
+    }
+}
+
+/// An iterator over the elapses of a [`CalendarEvent`].
+#[derive(Debug, Clone)]
+pub struct CalendarEventIter<'a, T> {
+    event: &'a CalendarEvent,
+    current: Option<T>,
+}
+
+impl<T: LocalDateTime> Iterator for CalendarEventIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current = self.event.next_elapse(self.current?);
+        self.current
+    }
+}
+
+impl<T: LocalDateTime> FusedIterator for CalendarEventIter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::{Date, PrimitiveDateTime};
+
+    use crate::CalendarEvent;
+
+    fn datetime(year: i32, month: time::Month, day: u8, hms: (u8, u8, u8)) -> PrimitiveDateTime {
+        let date = Date::from_calendar_date(year, month, day).unwrap();
+        date.with_hms(hms.0, hms.1, hms.2).unwrap()
+    }
+
+    fn upcoming(event: &str, after: PrimitiveDateTime, n: usize) -> Vec<PrimitiveDateTime> {
+        let event: CalendarEvent = event.parse().unwrap();
+        event.upcoming(after).take(n).collect()
+    }
+
+    #[test]
+    fn weekdays() {
+        let d0 = datetime(2024, January, 5, (10, 0, 0));
+        let dates = upcoming("Mon..Fri *-*-* 09:00:00", d0, 2);
+        let expected = vec![
+            datetime(2024, January, 8, (9, 0, 0)),
+            datetime(2024, January, 9, (9, 0, 0)),
+        ];
+        assert_eq!(dates, expected);
+    }
+
+    #[test]
+    fn last_days() {
+        let d0 = datetime(2024, January, 1, (0, 0, 0));
+        let dates = upcoming("*-02~01", d0, 2);
+        let expected = vec![
+            datetime(2024, February, 29, (0, 0, 0)),
+            datetime(2025, February, 28, (0, 0, 0)),
+        ];
+        assert_eq!(dates, expected);
+
+        // The last Monday in May.
+        let dates = upcoming("Mon *-05~07/1", d0, 2);
+        let days: Vec<_> = dates.iter().map(|x| (x.year(), x.day())).collect();
+        assert_eq!(days, vec![(2024, 27), (2025, 26)]);
+    }
+
+    #[test]
+    fn repetition() {
+        let d0 = datetime(2024, January, 1, (0, 0, 0));
+        let dates = upcoming("*:0/20", d0, 3);
+        let minutes: Vec<_> = dates.iter().map(|x| x.minute()).collect();
+        assert_eq!(minutes, vec![20, 40, 0]);
+
+        let dates = upcoming("*-*-1..10/3 12:00", d0, 4);
+        let days: Vec<_> = dates.iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![1, 4, 7, 10]);
+    }
+
+    #[test]
+    fn impossible() {
+        let d0 = datetime(2024, January, 1, (0, 0, 0));
+        assert!(upcoming("*-02-30", d0, 1).is_empty());
+        assert!(upcoming("2020-*-*", d0, 1).is_empty());
+    }
+}
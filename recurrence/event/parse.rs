@@ -0,0 +1,376 @@
+use std::str::FromStr;
+
+use time::UtcOffset;
+
+use super::Component;
+use crate::{CalendarEvent, ParseEventError, ParseEventErrorKind as Kind};
+
+const WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+const SPECIALS: [(&str, &str); 9] = [
+    ("minutely", "*-*-* *:*:00"),
+    ("hourly", "*-*-* *:00:00"),
+    ("daily", "*-*-* 00:00:00"),
+    ("weekly", "Mon *-*-* 00:00:00"),
+    ("monthly", "*-*-01 00:00:00"),
+    ("quarterly", "*-01,04,07,10-01 00:00:00"),
+    ("semiannually", "*-01,07-01 00:00:00"),
+    ("yearly", "*-01-01 00:00:00"),
+    ("annually", "*-01-01 00:00:00"),
+];
+
+/// Returns the byte offset of the substring in the input.
+fn offset_of(s: &str, part: &str) -> usize {
+    part.as_ptr() as usize - s.as_ptr() as usize
+}
+
+fn number(value: &str) -> Option<u16> {
+    match !value.is_empty() && value.bytes().all(|x| x.is_ascii_digit()) {
+        true => value.parse().ok(),
+        false => None,
+    }
+}
+
+/// Parses a weekday from its full or abbreviated name, numbered from 0 (Monday).
+fn weekday(value: &str) -> Option<u8> {
+    let position = WEEKDAYS.iter().position(|x| {
+        x.eq_ignore_ascii_case(value) || (value.len() == 3 && x[..3].eq_ignore_ascii_case(value))
+    });
+
+    position.map(|x| x as u8)
+}
+
+fn weekdays(item: &str, offset: usize) -> Result<u8, ParseEventError> {
+    let mut mask = 0;
+    let mut offset = offset;
+    for term in item.strip_suffix(',').unwrap_or(item).split(',') {
+        let invalid = ParseEventError::new(Kind::InvalidWeekday, offset);
+        let (start, stop) = match term.split_once("..") {
+            Some((start, stop)) => (weekday(start), weekday(stop)),
+            None => (weekday(term), weekday(term)),
+        };
+
+        match start.zip(stop) {
+            Some((start, stop)) if start <= stop => (start..=stop).for_each(|x| mask |= 1 << x),
+            _ => return Err(invalid),
+        }
+
+        offset += term.len() + 1;
+    }
+
+    Ok(mask)
+}
+
+/// Parses the timezone suffix: `UTC`, `GMT` or a fixed offset.
+fn timezone(value: &str) -> Option<UtcOffset> {
+    if value.eq_ignore_ascii_case("UTC") || value.eq_ignore_ascii_case("GMT") {
+        return Some(UtcOffset::UTC);
+    }
+
+    let (sign, value) = match value.as_bytes().first()? {
+        b'+' => (1, &value[1..]),
+        b'-' => (-1, &value[1..]),
+        _ => return None,
+    };
+
+    let (hours, minutes) = match (value.len(), value.split_once(':')) {
+        (_, Some((hours, minutes))) if hours.len() == 2 && minutes.len() == 2 => (hours, minutes),
+        (4, None) => value.split_at(2),
+        (2, None) => (value, "00"),
+        _ => return None,
+    };
+
+    let (hours, minutes) = (number(hours)?, number(minutes)?);
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    UtcOffset::from_hms(sign * hours as i8, sign * minutes as i8, 0).ok()
+}
+
+struct FieldParser {
+    kind: Kind,
+    min: u16,
+    max: u16,
+    year: bool,
+}
+
+impl FieldParser {
+    const YEAR: Self = Self::new(Kind::InvalidDate, 1970, 2199);
+    const MONTH: Self = Self::new(Kind::InvalidDate, 1, 12);
+    const DAY: Self = Self::new(Kind::InvalidDate, 1, 31);
+    const HOUR: Self = Self::new(Kind::InvalidTime, 0, 23);
+    const MINUTE: Self = Self::new(Kind::InvalidTime, 0, 59);
+    const SECOND: Self = Self::new(Kind::InvalidTime, 0, 59);
+
+    const fn new(kind: Kind, min: u16, max: u16) -> Self {
+        Self {
+            kind,
+            min,
+            max,
+            year: min == 1970,
+        }
+    }
+
+    /// Parses a value, reading two-digit years as 1970 to 2069.
+    fn value(&self, value: &str) -> Option<u16> {
+        let value = match number(value)? {
+            x if self.year && x < 70 => x + 2000,
+            x if self.year && x < 100 => x + 1900,
+            x => x,
+        };
+
+        (self.min..=self.max).contains(&value).then_some(value)
+    }
+
+    fn term(&self, term: &str, offset: usize) -> Result<Component, ParseEventError> {
+        let invalid = ParseEventError::new(self.kind, offset);
+        let (range, repeat) = match term.split_once('/') {
+            Some((range, repeat)) => {
+                let repeat = number(repeat).filter(|x| *x != 0).ok_or(invalid)?;
+                (range, Some(repeat))
+            }
+            None => (term, None),
+        };
+
+        if range == "*" && repeat.is_some() {
+            let (start, stop) = (self.min, None);
+            return Ok(Component {
+                start,
+                stop,
+                repeat,
+            });
+        }
+
+        let (start, stop) = match range.split_once("..") {
+            Some((start, stop)) => {
+                let start = self.value(start).ok_or(invalid)?;
+                let stop = self.value(stop).filter(|x| *x >= start).ok_or(invalid)?;
+                (start, Some(stop))
+            }
+            None => (self.value(range).ok_or(invalid)?, None),
+        };
+
+        Ok(Component {
+            start,
+            stop,
+            repeat,
+        })
+    }
+
+    /// Parses a comma-separated list of terms, returning an empty list for `*`.
+    fn parse(&self, item: &str, offset: usize) -> Result<Vec<Component>, ParseEventError> {
+        if item == "*" {
+            return Ok(Vec::new());
+        }
+
+        let mut offset = offset;
+        let mut components = Vec::new();
+        for term in item.split(',') {
+            components.push(self.term(term, offset)?);
+            offset += term.len() + 1;
+        }
+
+        components.sort_unstable();
+        components.dedup();
+        Ok(components)
+    }
+}
+
+impl CalendarEvent {
+    fn parse_date(&mut self, s: &str, item: &str) -> Result<(), ParseEventError> {
+        let offset = offset_of(s, item);
+        let invalid = ParseEventError::new(Kind::InvalidDate, offset);
+        let (head, day) = match item.split_once('~') {
+            Some((head, day)) => {
+                self.end_of_month = true;
+                (head, day)
+            }
+            None => item.rsplit_once('-').ok_or(invalid)?,
+        };
+
+        let (year, month) = match head.split_once('-') {
+            Some((year, month)) => (Some(year), month),
+            None => (None, head),
+        };
+
+        if let Some(year) = year {
+            self.year = FieldParser::YEAR.parse(year, offset)?;
+        }
+
+        self.month = FieldParser::MONTH.parse(month, offset_of(s, month))?;
+        self.day = FieldParser::DAY.parse(day, offset_of(s, day))?;
+        Ok(())
+    }
+
+    fn parse_time(&mut self, s: &str, item: &str) -> Result<(), ParseEventError> {
+        let invalid = ParseEventError::new(Kind::InvalidTime, offset_of(s, item));
+        let parts: Vec<_> = item.split(':').collect();
+        let (hour, minute, second) = match parts[..] {
+            [hour, minute] => (hour, minute, None),
+            [hour, minute, second] => (hour, minute, Some(second)),
+            _ => return Err(invalid),
+        };
+
+        self.hour = FieldParser::HOUR.parse(hour, offset_of(s, hour))?;
+        self.minute = FieldParser::MINUTE.parse(minute, offset_of(s, minute))?;
+        if let Some(second) = second {
+            self.second = FieldParser::SECOND.parse(second, offset_of(s, second))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for CalendarEvent {
+    type Err = ParseEventError;
+
+    /// Parses a calendar event in the syntax of `systemd.time(7)`.
+    ///
+    /// ```rust
+    /// # use timext::{CalendarEvent, ParseEventErrorKind};
+    /// let event: CalendarEvent = "Sat,Mon..Wed *-1..5-1,15 2,1:23".parse().unwrap();
+    /// assert_eq!(event.to_string(), "Mon..Wed,Sat *-01..05-01,15 01,02:23:00");
+    ///
+    /// let error = "*-13-01".parse::<CalendarEvent>().unwrap_err();
+    /// assert_eq!(error.kind(), ParseEventErrorKind::InvalidDate);
+    /// assert_eq!(error.offset(), 2);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut items: Vec<_> = s.split_whitespace().collect();
+        let mut offset = None;
+        if let Some(&last) = items.last().filter(|_| items.len() > 1) {
+            let timezone = last.starts_with(['+', '-']) || last.starts_with(char::is_alphabetic);
+            if timezone {
+                let error = ParseEventError::new(Kind::InvalidTimezone, offset_of(s, last));
+                offset = Some(self::timezone(last).ok_or(error)?);
+                items.pop();
+            }
+        }
+
+        if let [item] = items[..] {
+            if let Some((_, expansion)) = SPECIALS.iter().find(|x| x.0.eq_ignore_ascii_case(item)) {
+                let mut event: Self = expansion.parse().unwrap();
+                event.offset = offset;
+                return Ok(event);
+            }
+        }
+
+        let midnight = vec![Component {
+            start: 0,
+            stop: None,
+            repeat: None,
+        }];
+
+        let mut event = Self {
+            weekdays: 0,
+            year: Vec::new(),
+            month: Vec::new(),
+            day: Vec::new(),
+            end_of_month: false,
+            hour: midnight.clone(),
+            minute: midnight.clone(),
+            second: midnight,
+            offset,
+        };
+
+        // The weekdays, date and time must appear in this order.
+        let mut position = 0;
+        for item in items {
+            let offset = offset_of(s, item);
+            let unexpected = ParseEventError::new(Kind::UnexpectedToken, offset);
+            let next = match item {
+                _ if item.starts_with(char::is_alphabetic) => 1,
+                _ if item.contains(':') => 3,
+                _ => 2,
+            };
+
+            if next <= position {
+                return Err(unexpected);
+            }
+
+            match next {
+                1 => event.weekdays = weekdays(item, offset)?,
+                2 => event.parse_date(s, item)?,
+                _ => event.parse_time(s, item)?,
+            }
+
+            position = next;
+        }
+
+        if position == 0 {
+            return Err(ParseEventError::new(Kind::UnexpectedToken, s.len()));
+        }
+
+        Ok(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::UtcOffset;
+
+    use crate::{CalendarEvent, ParseEventErrorKind as Kind};
+
+    #[test]
+    fn normalize() {
+        let cases = [
+            (
+                "Sat,Thu,Mon..Wed,Sat..Sun",
+                "Mon..Thu,Sat,Sun *-*-* 00:00:00",
+            ),
+            ("Mon,Sun 12-*-* 2,1:23", "Mon,Sun 2012-*-* 01,02:23:00"),
+            ("Wed *-1", "Wed *-*-01 00:00:00"),
+            ("Wed..Wed,Wed *-1", "Wed *-*-01 00:00:00"),
+            ("Wed, 17:48", "Wed *-*-* 17:48:00"),
+            ("*-*-7/2 *:0/15:0", "*-*-07/2 *:00/15:00"),
+            ("*:*/20", "*-*-* *:00/20:00"),
+            ("2003-03-05 05:40", "2003-03-05 05:40:00"),
+            ("05:40", "*-*-* 05:40:00"),
+            ("*-02~01", "*-02~01 00:00:00"),
+            ("Mon *-05~07/1", "Mon *-05~07/1 00:00:00"),
+            ("*-*-1..5,3 9..17:00", "*-*-01..05,03 09..17:00:00"),
+            ("daily", "*-*-* 00:00:00"),
+            ("weekly UTC", "Mon *-*-* 00:00:00 UTC"),
+            ("semiannually", "*-01,07-01 00:00:00"),
+            ("12:00 +05:30", "*-*-* 12:00:00 +05:30"),
+            ("12:00 -0800", "*-*-* 12:00:00 -08:00"),
+        ];
+
+        for (input, expected) in cases {
+            let event: CalendarEvent = input.parse().unwrap();
+            assert_eq!(event.to_string(), expected, "{}", input);
+        }
+
+        let event: CalendarEvent = "*-*-* 12:00 +01".parse().unwrap();
+        assert_eq!(event.offset(), Some(UtcOffset::from_hms(1, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let parse = |x: &str| {
+            let error = x.parse::<CalendarEvent>().unwrap_err();
+            (error.kind(), error.offset())
+        };
+
+        assert_eq!(parse(""), (Kind::UnexpectedToken, 0));
+        assert_eq!(parse("Fri..Mon"), (Kind::InvalidWeekday, 0));
+        assert_eq!(parse("Mon,Foo 12:00"), (Kind::InvalidWeekday, 4));
+        assert_eq!(parse("*-*-32"), (Kind::InvalidDate, 4));
+        assert_eq!(parse("1969-01-01"), (Kind::InvalidDate, 0));
+        assert_eq!(parse("*-*-5..1"), (Kind::InvalidDate, 4));
+        assert_eq!(parse("*-*-*/0"), (Kind::InvalidDate, 4));
+        assert_eq!(parse("*:60"), (Kind::InvalidTime, 2));
+        assert_eq!(parse("12:00 Europe/Berlin"), (Kind::InvalidTimezone, 6));
+        assert_eq!(parse("12:00 +24:00"), (Kind::InvalidTimezone, 6));
+        assert_eq!(parse("12:00 *-*-*"), (Kind::UnexpectedToken, 6));
+    }
+}
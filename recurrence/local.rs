@@ -0,0 +1,48 @@
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+/// Sealed trait to prevent downstream implementations.
+mod sealed {
+    use time::{OffsetDateTime, PrimitiveDateTime};
+
+    pub trait Sealed {}
+    impl Sealed for PrimitiveDateTime {}
+    impl Sealed for OffsetDateTime {}
+}
+
+/// Date-time values whose recurrences are computed in local (wall-clock) time.
+pub trait LocalDateTime: sealed::Sealed + Copy + Ord {
+    /// Returns the wall-clock date and time.
+    fn local(self) -> PrimitiveDateTime;
+    /// Returns the offset of the value, if any.
+    fn local_offset(self) -> Option<UtcOffset>;
+    /// Returns a value at the wall-clock date and time with the offset of `self`.
+    fn with_local(self, local: PrimitiveDateTime) -> Self;
+}
+
+impl LocalDateTime for PrimitiveDateTime {
+    fn local(self) -> PrimitiveDateTime {
+        self
+    }
+
+    fn local_offset(self) -> Option<UtcOffset> {
+        None
+    }
+
+    fn with_local(self, local: PrimitiveDateTime) -> Self {
+        local
+    }
+}
+
+impl LocalDateTime for OffsetDateTime {
+    fn local(self) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(self.date(), self.time())
+    }
+
+    fn local_offset(self) -> Option<UtcOffset> {
+        Some(self.offset())
+    }
+
+    fn with_local(self, local: PrimitiveDateTime) -> Self {
+        local.assume_offset(self.offset())
+    }
+}
@@ -0,0 +1,16 @@
+mod local;
+
+mod billing;
+pub use billing::*;
+
+mod cron;
+pub use cron::*;
+
+mod event;
+pub use event::*;
+
+mod rrule;
+pub use rrule::*;
+
+mod series;
+pub use series::*;
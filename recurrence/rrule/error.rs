@@ -0,0 +1,64 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The kind of [`ParseRuleError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseRuleErrorKind {
+    /// A rule part that is not of the form `NAME=VALUE`.
+    MalformedPart,
+    /// A rule part with an unknown name.
+    UnknownPart,
+    /// A rule part that is repeated.
+    DuplicatePart,
+    /// A value that is malformed or out of range.
+    InvalidValue,
+    /// A rule without the `FREQ` rule part.
+    MissingFrequency,
+    /// A rule part that is not allowed in combination with the other rule parts.
+    InvalidCombination,
+}
+
+/// An error returned when parsing a [`RecurrenceRule`] fails.
+///
+/// [`RecurrenceRule`]: crate::RecurrenceRule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseRuleError {
+    kind: ParseRuleErrorKind,
+    offset: usize,
+}
+
+impl ParseRuleError {
+    pub(crate) const fn new(kind: ParseRuleErrorKind, offset: usize) -> Self {
+        Self { kind, offset }
+    }
+
+    /// Returns the kind of the error.
+    #[must_use]
+    pub const fn kind(&self) -> ParseRuleErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset of the input at which the error occurred.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for ParseRuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let message = match self.kind {
+            ParseRuleErrorKind::MalformedPart => "rule part is not of the form `NAME=VALUE`",
+            ParseRuleErrorKind::UnknownPart => "unknown rule part",
+            ParseRuleErrorKind::DuplicatePart => "repeated rule part",
+            ParseRuleErrorKind::InvalidValue => "invalid value",
+            ParseRuleErrorKind::MissingFrequency => "missing `FREQ` rule part",
+            ParseRuleErrorKind::InvalidCombination => "rule part is not allowed here",
+        };
+
+        write!(f, "{} at byte {}", message, self.offset)
+    }
+}
+
+impl Error for ParseRuleError {}
@@ -0,0 +1,522 @@
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+
+use time::{Date, Duration, Month, PrimitiveDateTime, Time, Weekday};
+
+use crate::recurrence::local::LocalDateTime;
+use crate::util::days_in_year_month;
+use crate::{Frequency, RecurrenceRule, RuleUntil};
+
+const fn days_in_year(year: i32) -> u16 {
+    match time::util::is_leap_year(year) {
+        true => 366,
+        false => 365,
+    }
+}
+
+/// Returns the Julian day of the first day of week 1 of the year, the first
+/// week with at least four days in the year.
+fn week_one(year: i32, week_start: Weekday) -> i64 {
+    let y = year as i64 - 1;
+    let jan1 = 1_721_426 + 365 * y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400);
+    let back = (jan1 - week_start.number_days_from_monday() as i64).rem_euclid(7);
+    match back <= 3 {
+        true => jan1 - back,
+        false => jan1 - back + 7,
+    }
+}
+
+/// An iterator over the occurrences of a [`RecurrenceRule`].
+///
+/// Occurrences are computed one period of the frequency at a time, and end
+/// with the rule's `COUNT` or `UNTIL`, or when they no longer fit into the
+/// range of `T`.
+#[derive(Debug, Clone)]
+pub struct RecurrenceRuleIter<T> {
+    rule: RecurrenceRule,
+    start: T,
+    local: PrimitiveDateTime,
+    base: PrimitiveDateTime,
+    until: Option<PrimitiveDateTime>,
+    period: i64,
+    pending: VecDeque<PrimitiveDateTime>,
+    emitted: u32,
+    done: bool,
+}
+
+impl<T: LocalDateTime> RecurrenceRuleIter<T> {
+    pub(crate) fn new(rule: &RecurrenceRule, start: T) -> Self {
+        let local = start.local();
+        let mut rule = rule.clone();
+
+        // Fill in the rule parts implied by the start, as defined by RFC 5545.
+        let frequency = rule.frequency;
+        let no_days = rule.by_week_no.is_empty()
+            && rule.by_year_day.is_empty()
+            && rule.by_month_day.is_empty()
+            && rule.by_day.is_empty();
+
+        if no_days {
+            match frequency {
+                Frequency::Yearly if rule.by_month.is_empty() => {
+                    rule.by_month.push(local.month() as u8);
+                    rule.by_month_day.push(local.day() as i8);
+                }
+                Frequency::Yearly | Frequency::Monthly => {
+                    rule.by_month_day.push(local.day() as i8);
+                }
+                Frequency::Weekly => rule.by_day.push((0, local.weekday())),
+                _ => {}
+            }
+        }
+
+        if frequency >= Frequency::Daily && rule.by_hour.is_empty() {
+            rule.by_hour.push(local.hour());
+        }
+
+        if frequency >= Frequency::Hourly && rule.by_minute.is_empty() {
+            rule.by_minute.push(local.minute());
+        }
+
+        if frequency >= Frequency::Minutely && rule.by_second.is_empty() {
+            rule.by_second.push(local.second());
+        }
+
+        for list in [&mut rule.by_hour, &mut rule.by_minute, &mut rule.by_second] {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        // Leap seconds are not representable, so a rule with only those never matches.
+        let leap = !rule.by_second.is_empty() && rule.by_second.iter().all(|x| *x == 60);
+
+        let (hour, minute, second) = local.as_hms();
+        let base = match frequency {
+            Frequency::Hourly => Time::from_hms(hour, 0, 0),
+            Frequency::Minutely => Time::from_hms(hour, minute, 0),
+            _ => Time::from_hms(hour, minute, second),
+        };
+
+        let base = local.replace_time(base.unwrap());
+        let until = rule.until.map(|until| match until {
+            RuleUntil::Date(date) => {
+                let time = Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap();
+                PrimitiveDateTime::new(date, time)
+            }
+            RuleUntil::Local(datetime) => datetime,
+            RuleUntil::Utc(datetime) => match start.local_offset() {
+                Some(offset) => {
+                    let datetime = datetime.assume_utc().to_offset(offset);
+                    PrimitiveDateTime::new(datetime.date(), datetime.time())
+                }
+                None => datetime,
+            },
+        });
+
+        Self {
+            rule,
+            start,
+            local,
+            base,
+            until,
+            period: 0,
+            pending: VecDeque::new(),
+            emitted: 0,
+            done: leap,
+        }
+    }
+
+    fn matches_week_no(&self, date: Date) -> bool {
+        let week_start = self.rule.week_start;
+        let day = date.to_julian_day() as i64;
+        let mut year = date.year();
+        let mut start = week_one(year, week_start);
+        if day < start {
+            year -= 1;
+            start = week_one(year, week_start);
+        } else if day >= week_one(year + 1, week_start) {
+            year += 1;
+            start = week_one(year, week_start);
+        }
+
+        let weeks = (week_one(year + 1, week_start) - start) / 7;
+        let week = (day - start) / 7 + 1;
+        let matches = |n: i64| n == week || n == week - weeks - 1;
+        self.rule.by_week_no.iter().any(|&n| matches(n as i64))
+    }
+
+    fn matches_date(&self, date: Date) -> bool {
+        let rule = &self.rule;
+        let month = date.month();
+        if !rule.by_month.is_empty() && !rule.by_month.contains(&(month as u8)) {
+            return false;
+        }
+
+        if !rule.by_week_no.is_empty() && !self.matches_week_no(date) {
+            return false;
+        }
+
+        let (day, length) = (date.ordinal() as i16, days_in_year(date.year()) as i16);
+        let matches = |n: i16| n == day || n == day - length - 1;
+        if !rule.by_year_day.is_empty() && !rule.by_year_day.iter().any(|&n| matches(n)) {
+            return false;
+        }
+
+        let (day, length) = (
+            date.day() as i16,
+            days_in_year_month(date.year(), month) as i16,
+        );
+        let matches = |n: i16| n == day || n == day - length - 1;
+        if !rule.by_month_day.is_empty() && !rule.by_month_day.iter().any(|&n| matches(n as i16)) {
+            return false;
+        }
+
+        // Ordinal weekdays are counted within the year for yearly rules without
+        // `BYMONTH`, and within the month otherwise.
+        let (day, length) = match rule.frequency {
+            Frequency::Yearly if rule.by_month.is_empty() => {
+                (date.ordinal() as i16, days_in_year(date.year()) as i16)
+            }
+            _ => (day, length),
+        };
+
+        let matches = |n: i8, weekday| {
+            weekday == date.weekday()
+                && match n {
+                    0 => true,
+                    n if n > 0 => (day - 1) / 7 + 1 == n as i16,
+                    n => (length - day) / 7 + 1 == -n as i16,
+                }
+        };
+
+        rule.by_day.is_empty() || rule.by_day.iter().any(|&(n, x)| matches(n, x))
+    }
+
+    /// Returns the occurrences of the period at the date, sorted.
+    fn expand(
+        &self,
+        dates: impl Iterator<Item = Date>,
+        hours: &[u8],
+        minutes: &[u8],
+        seconds: &[u8],
+    ) -> Vec<PrimitiveDateTime> {
+        let nanosecond = self.local.nanosecond();
+        let mut occurrences = Vec::new();
+        for date in dates.filter(|x| self.matches_date(*x)) {
+            for &hour in hours {
+                for &minute in minutes {
+                    for &second in seconds {
+                        // Leap seconds are not representable and are skipped.
+                        let time = Time::from_hms_nano(hour, minute, second, nanosecond);
+                        if let Ok(time) = time {
+                            occurrences.push(PrimitiveDateTime::new(date, time));
+                        }
+                    }
+                }
+            }
+        }
+
+        if self.rule.by_set_pos.is_empty() {
+            return occurrences;
+        }
+
+        let len = occurrences.len() as i64;
+        let mut selected: Vec<_> = self
+            .rule
+            .by_set_pos
+            .iter()
+            .map(|&n| match n > 0 {
+                true => n as i64 - 1,
+                false => len + n as i64,
+            })
+            .filter(|x| (0..len).contains(x))
+            .map(|x| occurrences[x as usize])
+            .collect();
+
+        selected.sort_unstable();
+        selected.dedup();
+        selected
+    }
+
+    /// Returns the occurrences of the next period, or `None` if the period is out of range.
+    fn next_period(&mut self) -> Option<Vec<PrimitiveDateTime>> {
+        let rule = &self.rule;
+        let step = self.period.checked_mul(rule.interval as i64)?;
+        let days = |start: Date, len: u16| {
+            let dates = (0..len).map_while(move |x| start.checked_add(Duration::days(x as i64)));
+            self.expand(dates, &rule.by_hour, &rule.by_minute, &rule.by_second)
+        };
+
+        let occurrences = match rule.frequency {
+            Frequency::Yearly => {
+                let year = i32::try_from(self.local.year() as i64 + step).ok()?;
+                let start = Date::from_calendar_date(year, Month::January, 1).ok()?;
+                days(start, days_in_year(year))
+            }
+            Frequency::Monthly => {
+                let month = self.local.month() as i64 - 1 + step;
+                let year = i32::try_from(self.local.year() as i64 + month.div_euclid(12)).ok()?;
+                let month = Month::try_from(month.rem_euclid(12) as u8 + 1).unwrap();
+                let start = Date::from_calendar_date(year, month, 1).ok()?;
+                days(start, days_in_year_month(year, month) as u16)
+            }
+            Frequency::Weekly => {
+                let back = self.local.weekday().number_days_from_monday() as i64
+                    - rule.week_start.number_days_from_monday() as i64;
+                let offset = step.checked_mul(7)? - back.rem_euclid(7);
+                let start = self.local.date().checked_add(Duration::days(offset))?;
+                days(start, 7)
+            }
+            Frequency::Daily => {
+                let start = self.local.date().checked_add(Duration::days(step))?;
+                days(start, 1)
+            }
+            _ => return self.next_subdaily_period(step),
+        };
+
+        self.period += 1;
+        Some(occurrences)
+    }
+
+    fn next_subdaily_period(&mut self, step: i64) -> Option<Vec<PrimitiveDateTime>> {
+        let rule = &self.rule;
+        let unit = match rule.frequency {
+            Frequency::Hourly => 3600,
+            Frequency::Minutely => 60,
+            _ => 1,
+        };
+
+        let current = self
+            .base
+            .checked_add(Duration::seconds(step.checked_mul(unit)?))?;
+        let (hour, minute, second) = current.as_hms();
+        let limits = |list: &[u8], x| list.is_empty() || list.contains(&x);
+
+        // Skip over the whole day, hour or minute that cannot match.
+        let next = if !self.matches_date(current.date()) {
+            Some(current.date().next_day()?.midnight())
+        } else if !limits(&rule.by_hour, hour) {
+            Some(current.replace_time(Time::from_hms(hour, 0, 0).unwrap()) + Duration::HOUR)
+        } else if rule.frequency < Frequency::Hourly && !limits(&rule.by_minute, minute) {
+            let time = Time::from_hms(hour, minute, 0).unwrap();
+            Some(current.replace_time(time) + Duration::MINUTE)
+        } else {
+            None
+        };
+
+        if let Some(next) = next {
+            let step = rule.interval as i64 * unit;
+            let periods = ((next - self.base).whole_seconds() + step - 1) / step;
+            self.period = periods.max(self.period + 1);
+            return Some(Vec::new());
+        }
+
+        let (hours, minutes, seconds) = match rule.frequency {
+            Frequency::Hourly => (&[hour][..], &rule.by_minute[..], &rule.by_second[..]),
+            Frequency::Minutely => (&[hour][..], &[minute][..], &rule.by_second[..]),
+            _ if limits(&rule.by_second, second) => (&[hour][..], &[minute][..], &[second][..]),
+            _ => (&[][..], &[][..], &[][..]),
+        };
+
+        let dates = std::iter::once(current.date());
+        let occurrences = self.expand(dates, hours, minutes, seconds);
+        self.period += 1;
+        Some(occurrences)
+    }
+}
+
+impl<T: LocalDateTime> Iterator for RecurrenceRuleIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.done || self.rule.count.is_some_and(|x| self.emitted >= x) {
+                self.done = true;
+                return None;
+            }
+
+            let Some(next) = self.pending.pop_front() else {
+                match self.next_period() {
+                    Some(occurrences) => self.pending.extend(occurrences),
+                    None => self.done = true,
+                }
+
+                continue;
+            };
+
+            if next < self.local {
+                continue;
+            }
+
+            if self.until.is_some_and(|x| next > x) {
+                self.done = true;
+                return None;
+            }
+
+            self.emitted += 1;
+            return Some(self.start.with_local(next));
+        }
+    }
+}
+
+impl<T: LocalDateTime> FusedIterator for RecurrenceRuleIter<T> {}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::{Date, PrimitiveDateTime, UtcOffset};
+
+    use crate::RecurrenceRule;
+
+    fn expand(rule: &str, start: PrimitiveDateTime, n: usize) -> Vec<PrimitiveDateTime> {
+        let rule: RecurrenceRule = rule.parse().unwrap();
+        rule.occurrences(start).take(n).collect()
+    }
+
+    fn datetime(year: i32, month: time::Month, day: u8, hour: u8) -> PrimitiveDateTime {
+        let date = Date::from_calendar_date(year, month, day).unwrap();
+        date.with_hms(hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn daily_count() {
+        let d0 = datetime(1997, September, 2, 9);
+        let dates = expand("FREQ=DAILY;COUNT=10", d0, 100);
+        assert_eq!(dates.len(), 10);
+        assert_eq!(dates[9], datetime(1997, September, 11, 9));
+    }
+
+    #[test]
+    fn skip_invalid() {
+        let d0 = datetime(2024, January, 31, 9);
+        let dates = expand("FREQ=MONTHLY;COUNT=4", d0, 100);
+        let months: Vec<_> = dates.iter().map(|x| x.month()).collect();
+        assert_eq!(months, vec![January, March, May, July]);
+
+        let dates = expand("FREQ=MONTHLY;BYMONTHDAY=-1;COUNT=3", d0, 100);
+        let days: Vec<_> = dates.iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![31, 29, 31]);
+    }
+
+    #[test]
+    fn weekly_until() {
+        // RFC 5545: Weekly on Tuesday and Thursday for five weeks.
+        let d0 = datetime(1997, September, 2, 9);
+        let dates = expand(
+            "FREQ=WEEKLY;UNTIL=19971007T000000Z;WKST=SU;BYDAY=TU,TH",
+            d0,
+            100,
+        );
+        assert_eq!(dates.len(), 10);
+        assert_eq!(dates[9], datetime(1997, October, 2, 9));
+    }
+
+    #[test]
+    fn week_start() {
+        // RFC 5545: The effect of changing the week start.
+        let d0 = datetime(1997, August, 5, 9);
+        let dates = expand(
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO",
+            d0,
+            100,
+        );
+        let days: Vec<_> = dates.iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![5, 10, 19, 24]);
+
+        let dates = expand(
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU",
+            d0,
+            100,
+        );
+        let days: Vec<_> = dates.iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![5, 17, 19, 31]);
+    }
+
+    #[test]
+    fn monthly_ordinal() {
+        // RFC 5545: Monthly on the second-to-last Monday of the month for 6 months.
+        let d0 = datetime(1997, September, 22, 9);
+        let dates = expand("FREQ=MONTHLY;COUNT=6;BYDAY=-2MO", d0, 100);
+        let days: Vec<_> = dates.iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![22, 20, 17, 22, 19, 16]);
+    }
+
+    #[test]
+    fn yearly_week_no() {
+        // RFC 5545: Monday of week number 20.
+        let d0 = datetime(1997, May, 12, 9);
+        let dates = expand("FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO", d0, 3);
+        let expected = vec![
+            datetime(1997, May, 12, 9),
+            datetime(1998, May, 11, 9),
+            datetime(1999, May, 17, 9),
+        ];
+        assert_eq!(dates, expected);
+    }
+
+    #[test]
+    fn set_position() {
+        // RFC 5545: The last work day of the month.
+        let d0 = datetime(1997, September, 29, 9);
+        let dates = expand("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1", d0, 4);
+        let days: Vec<_> = dates.iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![30, 31, 28, 31]);
+    }
+
+    #[test]
+    fn friday_thirteenth() {
+        let d0 = datetime(1997, September, 2, 9);
+        let dates = expand("FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13", d0, 3);
+        let expected = vec![
+            datetime(1998, February, 13, 9),
+            datetime(1998, March, 13, 9),
+            datetime(1998, November, 13, 9),
+        ];
+        assert_eq!(dates, expected);
+    }
+
+    #[test]
+    fn subdaily() {
+        // RFC 5545: Every 20 minutes from 9:00 AM to 4:40 PM every day.
+        let d0 = datetime(1997, September, 2, 9);
+        let rule = "FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10,11,12,13,14,15,16";
+        let dates = expand(rule, d0, 25);
+        assert_eq!(
+            dates[23],
+            datetime(1997, September, 2, 16) + time::Duration::minutes(40)
+        );
+        assert_eq!(dates[24], datetime(1997, September, 3, 9));
+
+        let dates = expand("FREQ=HOURLY;INTERVAL=3;UNTIL=19970902T170000", d0, 100);
+        assert_eq!(dates.len(), 3);
+    }
+
+    #[test]
+    fn impossible() {
+        let d0 = datetime(2024, January, 1, 0);
+        let dates = expand("FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30", d0, 1);
+        assert!(dates.is_empty());
+    }
+
+    #[test]
+    fn leap_second() {
+        let d0 = datetime(2024, January, 1, 0);
+        for frequency in ["SECONDLY", "MINUTELY", "HOURLY", "DAILY"] {
+            let rule = format!("FREQ={};BYSECOND=60", frequency);
+            assert!(expand(&rule, d0, 1).is_empty());
+        }
+
+        let dates = expand("FREQ=MINUTELY;BYSECOND=30,60", d0, 2);
+        assert_eq!(dates[1], d0 + time::Duration::seconds(90));
+    }
+
+    #[test]
+    fn offset_until() {
+        let offset = UtcOffset::from_hms(-5, 0, 0).unwrap();
+        let d0 = datetime(2024, January, 1, 9).assume_offset(offset);
+        let rule: RecurrenceRule = "FREQ=DAILY;UNTIL=20240103T140000Z".parse().unwrap();
+        let dates: Vec<_> = rule.occurrences(d0).collect();
+        assert_eq!(dates.len(), 3);
+        assert!(dates.iter().all(|x| x.offset() == offset && x.hour() == 9));
+    }
+}
@@ -0,0 +1,280 @@
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+use time::{Date, PrimitiveDateTime, Weekday};
+
+use crate::recurrence::local::LocalDateTime;
+
+mod error;
+pub use error::*;
+
+mod expand;
+pub use expand::*;
+
+mod parse;
+
+mod set;
+pub use set::*;
+
+/// The frequency of a [`RecurrenceRule`], ordered from the shortest to the longest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Frequency {
+    /// Every second, `FREQ=SECONDLY`.
+    Secondly,
+    /// Every minute, `FREQ=MINUTELY`.
+    Minutely,
+    /// Every hour, `FREQ=HOURLY`.
+    Hourly,
+    /// Every day, `FREQ=DAILY`.
+    Daily,
+    /// Every week, starting on the week start, `FREQ=WEEKLY`.
+    Weekly,
+    /// Every month, `FREQ=MONTHLY`.
+    Monthly,
+    /// Every year, `FREQ=YEARLY`.
+    Yearly,
+}
+
+impl Frequency {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Secondly => "SECONDLY",
+            Self::Minutely => "MINUTELY",
+            Self::Hourly => "HOURLY",
+            Self::Daily => "DAILY",
+            Self::Weekly => "WEEKLY",
+            Self::Monthly => "MONTHLY",
+            Self::Yearly => "YEARLY",
+        }
+    }
+}
+
+/// The inclusive upper bound of a [`RecurrenceRule`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleUntil {
+    /// The last day on which occurrences may fall, e.g. `UNTIL=20240131`.
+    Date(Date),
+    /// A wall-clock date and time, e.g. `UNTIL=20240131T090000`.
+    Local(PrimitiveDateTime),
+    /// A date and time in UTC, e.g. `UNTIL=20240131T090000Z`.
+    ///
+    /// It is compared as wall-clock time when expanding a `PrimitiveDateTime`.
+    Utc(PrimitiveDateTime),
+}
+
+/// An RFC 5545 recurrence rule, e.g. `FREQ=MONTHLY;BYDAY=-1FR;COUNT=12`.
+///
+/// Rules are parsed with [`str::parse`], formatted with [`Display`], and
+/// expanded from a start with [`RecurrenceRule::occurrences`]. Dates that do
+/// not exist, e.g. the 30th of February, are skipped instead of clamped.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::RecurrenceRule;
+/// let rule: RecurrenceRule = "FREQ=MONTHLY;BYDAY=-1FR;COUNT=3".parse().unwrap();
+/// let d0 = Date::from_calendar_date(2024, January, 1).unwrap();
+/// let days: Vec<_> = rule.occurrences(d0.midnight()).map(|x| x.day()).collect();
+/// assert_eq!(days, vec![26, 23, 29]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecurrenceRule {
+    frequency: Frequency,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<RuleUntil>,
+    by_second: Vec<u8>,
+    by_minute: Vec<u8>,
+    by_hour: Vec<u8>,
+    by_day: Vec<(i8, Weekday)>,
+    by_month_day: Vec<i8>,
+    by_year_day: Vec<i16>,
+    by_week_no: Vec<i8>,
+    by_month: Vec<u8>,
+    by_set_pos: Vec<i16>,
+    week_start: Weekday,
+}
+
+impl RecurrenceRule {
+    /// Creates a new `RecurrenceRule` with the frequency and no other rule parts.
+    #[must_use]
+    pub const fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            count: None,
+            until: None,
+            by_second: Vec::new(),
+            by_minute: Vec::new(),
+            by_hour: Vec::new(),
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_year_day: Vec::new(),
+            by_week_no: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: Weekday::Monday,
+        }
+    }
+
+    /// Sets the number of periods between occurrences.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval is zero.
+    #[must_use]
+    pub fn with_interval(mut self, interval: u32) -> Self {
+        assert_ne!(interval, 0, "interval of `timext::RecurrenceRule` is zero");
+        self.interval = interval;
+        self
+    }
+
+    /// Limits the rule to the provided number of occurrences, replacing any `UNTIL`.
+    #[must_use]
+    pub fn with_count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self.until = None;
+        self
+    }
+
+    /// Limits the rule to the occurrences up to the bound, replacing any `COUNT`.
+    #[must_use]
+    pub fn with_until(mut self, until: RuleUntil) -> Self {
+        self.until = Some(until);
+        self.count = None;
+        self
+    }
+
+    /// Sets the day on which the week starts, used by `WEEKLY` and `BYWEEKNO`.
+    #[must_use]
+    pub fn with_week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// Returns the frequency of the rule.
+    #[must_use]
+    pub const fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// Returns the number of periods between occurrences.
+    #[must_use]
+    pub const fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Returns the number of occurrences, if limited by `COUNT`.
+    #[must_use]
+    pub const fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    /// Returns the inclusive upper bound, if limited by `UNTIL`.
+    #[must_use]
+    pub const fn until(&self) -> Option<RuleUntil> {
+        self.until
+    }
+
+    /// Returns the day on which the week starts.
+    #[must_use]
+    pub const fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+
+    /// Returns an iterator over the occurrences of the rule starting at `start`.
+    ///
+    /// Only the values matching the rule are yielded, so the start itself is
+    /// not an occurrence unless it matches, see [`RecurrenceSet`] otherwise.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::RecurrenceRule;
+    /// let rule: RecurrenceRule = "FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29".parse().unwrap();
+    /// let d0 = Date::from_calendar_date(2023, January, 1).unwrap();
+    /// let years: Vec<_> = rule.occurrences(d0.midnight()).take(2).map(|x| x.year()).collect();
+    /// assert_eq!(years, vec![2024, 2028]);
+    /// ```
+    #[must_use]
+    pub fn occurrences<T: LocalDateTime>(&self, start: T) -> RecurrenceRuleIter<T> {
+        RecurrenceRuleIter::new(self, start)
+    }
+}
+
+const fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday => "MO",
+        Weekday::Tuesday => "TU",
+        Weekday::Wednesday => "WE",
+        Weekday::Thursday => "TH",
+        Weekday::Friday => "FR",
+        Weekday::Saturday => "SA",
+        Weekday::Sunday => "SU",
+    }
+}
+
+fn write_list<T: Display>(output: &mut String, name: &str, values: &[T]) -> FmtResult {
+    if values.is_empty() {
+        return Ok(());
+    }
+
+    write!(output, ";{}=", name)?;
+    for (i, value) in values.iter().enumerate() {
+        match i {
+            0 => write!(output, "{}", value),
+            _ => write!(output, ",{}", value),
+        }?;
+    }
+
+    Ok(())
+}
+
+impl Display for RecurrenceRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut output = format!("FREQ={}", self.frequency.name());
+        if self.interval != 1 {
+            write!(output, ";INTERVAL={}", self.interval)?;
+        }
+
+        if let Some(count) = self.count {
+            write!(output, ";COUNT={}", count)?;
+        }
+
+        if let Some(until) = self.until {
+            let (date, time, utc) = match until {
+                RuleUntil::Date(date) => (date, None, ""),
+                RuleUntil::Local(datetime) => (datetime.date(), Some(datetime.time()), ""),
+                RuleUntil::Utc(datetime) => (datetime.date(), Some(datetime.time()), "Z"),
+            };
+
+            let (year, month, day) = (date.year(), date.month() as u8, date.day());
+            write!(output, ";UNTIL={:04}{:02}{:02}", year, month, day)?;
+            if let Some(time) = time {
+                let (hour, minute, second) = time.as_hms();
+                write!(output, "T{:02}{:02}{:02}{}", hour, minute, second, utc)?;
+            }
+        }
+
+        let by_day: Vec<_> = self
+            .by_day
+            .iter()
+            .map(|&(n, weekday)| match n {
+                0 => weekday_name(weekday).to_string(),
+                n => format!("{}{}", n, weekday_name(weekday)),
+            })
+            .collect();
+
+        write_list(&mut output, "BYSECOND", &self.by_second)?;
+        write_list(&mut output, "BYMINUTE", &self.by_minute)?;
+        write_list(&mut output, "BYHOUR", &self.by_hour)?;
+        write_list(&mut output, "BYDAY", &by_day)?;
+        write_list(&mut output, "BYMONTHDAY", &self.by_month_day)?;
+        write_list(&mut output, "BYYEARDAY", &self.by_year_day)?;
+        write_list(&mut output, "BYWEEKNO", &self.by_week_no)?;
+        write_list(&mut output, "BYMONTH", &self.by_month)?;
+        write_list(&mut output, "BYSETPOS", &self.by_set_pos)?;
+        if self.week_start != Weekday::Monday {
+            write!(output, ";WKST={}", weekday_name(self.week_start))?;
+        }
+
+        f.pad(&output)
+    }
+}
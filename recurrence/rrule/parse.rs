@@ -0,0 +1,313 @@
+use std::str::FromStr;
+
+use time::{Date, Month, PrimitiveDateTime, Time, Weekday};
+
+use crate::{Frequency, ParseRuleError, ParseRuleErrorKind as Kind, RecurrenceRule, RuleUntil};
+
+const PARTS: [&str; 14] = [
+    "FREQ",
+    "UNTIL",
+    "COUNT",
+    "INTERVAL",
+    "BYSECOND",
+    "BYMINUTE",
+    "BYHOUR",
+    "BYDAY",
+    "BYMONTHDAY",
+    "BYYEARDAY",
+    "BYWEEKNO",
+    "BYMONTH",
+    "BYSETPOS",
+    "WKST",
+];
+
+fn parse_frequency(value: &str) -> Option<Frequency> {
+    let frequency = match value.to_ascii_uppercase().as_str() {
+        "SECONDLY" => Frequency::Secondly,
+        "MINUTELY" => Frequency::Minutely,
+        "HOURLY" => Frequency::Hourly,
+        "DAILY" => Frequency::Daily,
+        "WEEKLY" => Frequency::Weekly,
+        "MONTHLY" => Frequency::Monthly,
+        "YEARLY" => Frequency::Yearly,
+        _ => return None,
+    };
+
+    Some(frequency)
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    let weekday = match value.to_ascii_uppercase().as_str() {
+        "MO" => Weekday::Monday,
+        "TU" => Weekday::Tuesday,
+        "WE" => Weekday::Wednesday,
+        "TH" => Weekday::Thursday,
+        "FR" => Weekday::Friday,
+        "SA" => Weekday::Saturday,
+        "SU" => Weekday::Sunday,
+        _ => return None,
+    };
+
+    Some(weekday)
+}
+
+/// Parses an optionally signed integer whose absolute value is in the range.
+fn parse_number<T>(value: &str, min: i32, max: i32, signed: bool) -> Option<T>
+where
+    T: TryFrom<i32>,
+{
+    let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+    if digits.is_empty() || !digits.bytes().all(|x| x.is_ascii_digit()) {
+        return None;
+    }
+
+    if !signed && digits.len() != value.len() {
+        return None;
+    }
+
+    let number: i32 = value.parse().ok()?;
+    match (min as u32..=max as u32).contains(&number.unsigned_abs()) {
+        true => T::try_from(number).ok(),
+        false => None,
+    }
+}
+
+fn parse_by_day(value: &str) -> Option<(i8, Weekday)> {
+    let split = value.len().checked_sub(2)?;
+    let weekday = parse_weekday(value.get(split..)?)?;
+    match &value[..split] {
+        "" => Some((0, weekday)),
+        n => Some((parse_number(n, 1, 53, true)?, weekday)),
+    }
+}
+
+fn parse_until(value: &str) -> Option<RuleUntil> {
+    let digits = |x: &str| x.bytes().all(|x| x.is_ascii_digit());
+    let (date, time) = match value.split_once(['T', 't']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (value, None),
+    };
+
+    if date.len() != 8 || !digits(date) {
+        return None;
+    }
+
+    let year = date[..4].parse().ok()?;
+    let month = Month::try_from(date[4..6].parse::<u8>().ok()?).ok()?;
+    let date = Date::from_calendar_date(year, month, date[6..].parse().ok()?).ok()?;
+    let Some(time) = time else {
+        return Some(RuleUntil::Date(date));
+    };
+
+    let (time, utc) = match time.strip_suffix(['Z', 'z']) {
+        Some(time) => (time, true),
+        None => (time, false),
+    };
+
+    if time.len() != 6 || !digits(time) {
+        return None;
+    }
+
+    let (hour, minute, second) = (&time[..2], &time[2..4], &time[4..]);
+    let time = Time::from_hms(
+        hour.parse().ok()?,
+        minute.parse().ok()?,
+        second.parse().ok()?,
+    );
+    let datetime = PrimitiveDateTime::new(date, time.ok()?);
+    match utc {
+        true => Some(RuleUntil::Utc(datetime)),
+        false => Some(RuleUntil::Local(datetime)),
+    }
+}
+
+/// Parses a comma-separated list, reporting the offset of the first invalid item.
+fn parse_list<T>(
+    value: &str,
+    offset: usize,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<Vec<T>, ParseRuleError> {
+    let mut offset = offset;
+    let mut values = Vec::new();
+    for item in value.split(',') {
+        let value = parse(item).ok_or(ParseRuleError::new(Kind::InvalidValue, offset))?;
+        values.push(value);
+        offset += item.len() + 1;
+    }
+
+    Ok(values)
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = ParseRuleError;
+
+    /// Parses the value of an RFC 5545 `RRULE` property, optionally prefixed by `RRULE:`.
+    ///
+    /// ```rust
+    /// # use timext::{Frequency, RecurrenceRule};
+    /// let rule: RecurrenceRule = "RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH".parse().unwrap();
+    /// assert_eq!(rule.frequency(), Frequency::Weekly);
+    /// assert_eq!(rule.to_string(), "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = ParseRuleError::new;
+        let mut offset = match s.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => 6,
+            _ => 0,
+        };
+
+        let mut frequency = None;
+        let mut offsets = [None; PARTS.len()];
+        let mut rule = Self::new(Frequency::Yearly);
+        for part in s[offset..].split(';') {
+            let start = offset;
+            offset += part.len() + 1;
+            if part.is_empty() {
+                continue;
+            }
+
+            let (name, value) = part
+                .split_once('=')
+                .ok_or(error(Kind::MalformedPart, start))?;
+
+            let name = name.to_ascii_uppercase();
+            let index = PARTS
+                .iter()
+                .position(|x| *x == name)
+                .ok_or(error(Kind::UnknownPart, start))?;
+
+            if offsets[index].replace(start).is_some() {
+                return Err(error(Kind::DuplicatePart, start));
+            }
+
+            let at = start + name.len() + 1;
+            let invalid = error(Kind::InvalidValue, at);
+            match PARTS[index] {
+                "FREQ" => frequency = Some(parse_frequency(value).ok_or(invalid)?),
+                "UNTIL" => rule.until = Some(parse_until(value).ok_or(invalid)?),
+                "COUNT" => {
+                    let count = parse_number(value, 0, i32::MAX, false);
+                    rule.count = Some(count.ok_or(invalid)?);
+                }
+                "INTERVAL" => {
+                    let interval = parse_number(value, 1, i32::MAX, false);
+                    rule.interval = interval.ok_or(invalid)?;
+                }
+                "BYSECOND" => {
+                    rule.by_second = parse_list(value, at, |x| parse_number(x, 0, 60, false))?
+                }
+                "BYMINUTE" => {
+                    rule.by_minute = parse_list(value, at, |x| parse_number(x, 0, 59, false))?
+                }
+                "BYHOUR" => {
+                    rule.by_hour = parse_list(value, at, |x| parse_number(x, 0, 23, false))?
+                }
+                "BYDAY" => rule.by_day = parse_list(value, at, parse_by_day)?,
+                "BYMONTHDAY" => {
+                    rule.by_month_day = parse_list(value, at, |x| parse_number(x, 1, 31, true))?
+                }
+                "BYYEARDAY" => {
+                    rule.by_year_day = parse_list(value, at, |x| parse_number(x, 1, 366, true))?
+                }
+                "BYWEEKNO" => {
+                    rule.by_week_no = parse_list(value, at, |x| parse_number(x, 1, 53, true))?
+                }
+                "BYMONTH" => {
+                    rule.by_month = parse_list(value, at, |x| parse_number(x, 1, 12, false))?
+                }
+                "BYSETPOS" => {
+                    rule.by_set_pos = parse_list(value, at, |x| parse_number(x, 1, 366, true))?
+                }
+                "WKST" => rule.week_start = parse_weekday(value).ok_or(invalid)?,
+                _ => unreachable!(),
+            }
+        }
+
+        rule.frequency = frequency.ok_or(error(Kind::MissingFrequency, s.len()))?;
+        let invalid = |name| {
+            let index = PARTS.iter().position(|x| *x == name).unwrap();
+            Err(error(Kind::InvalidCombination, offsets[index].unwrap_or(0)))
+        };
+
+        let frequency = rule.frequency;
+        if rule.count.is_some() && rule.until.is_some() {
+            return invalid("UNTIL");
+        }
+
+        if !rule.by_week_no.is_empty() && frequency != Frequency::Yearly {
+            return invalid("BYWEEKNO");
+        }
+
+        let yearly_or_subdaily = frequency == Frequency::Yearly || frequency < Frequency::Daily;
+        if !rule.by_year_day.is_empty() && !yearly_or_subdaily {
+            return invalid("BYYEARDAY");
+        }
+
+        if !rule.by_month_day.is_empty() && frequency == Frequency::Weekly {
+            return invalid("BYMONTHDAY");
+        }
+
+        let ordinal = rule.by_day.iter().any(|x| x.0 != 0);
+        let monthly_or_yearly = frequency == Frequency::Monthly || frequency == Frequency::Yearly;
+        if ordinal && (!monthly_or_yearly || !rule.by_week_no.is_empty()) {
+            return invalid("BYDAY");
+        }
+
+        let other = offsets[4..12].iter().any(Option::is_some);
+        if !rule.by_set_pos.is_empty() && !other {
+            return invalid("BYSETPOS");
+        }
+
+        Ok(rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ParseRuleErrorKind as Kind, RecurrenceRule};
+
+    #[test]
+    fn round_trip() {
+        let rules = [
+            "FREQ=DAILY;COUNT=10",
+            "FREQ=WEEKLY;UNTIL=19971224T000000Z;BYDAY=TU,TH;WKST=SU",
+            "FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU",
+            "FREQ=YEARLY;UNTIL=20240131;BYDAY=SU,MO;BYMONTH=1",
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-2",
+            "FREQ=HOURLY;INTERVAL=3;UNTIL=19970902T170000",
+        ];
+
+        for rule in rules {
+            let parsed: RecurrenceRule = rule.parse().unwrap();
+            assert_eq!(parsed.to_string(), rule);
+        }
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let parse = |x: &str| x.parse::<RecurrenceRule>().unwrap_err();
+        let error = parse("COUNT=10");
+        assert_eq!((error.kind(), error.offset()), (Kind::MissingFrequency, 8));
+        let error = parse("FREQ=DAILY;BYMONTH=1,13");
+        assert_eq!((error.kind(), error.offset()), (Kind::InvalidValue, 21));
+        let error = parse("FREQ=DAILY;COUNT=1;COUNT=2");
+        assert_eq!((error.kind(), error.offset()), (Kind::DuplicatePart, 19));
+        let error = parse("FREQ=DAILY;FOO=1");
+        assert_eq!((error.kind(), error.offset()), (Kind::UnknownPart, 11));
+        let error = parse("FREQ=WEEKLY;BYDAY=1MO");
+        assert_eq!(
+            (error.kind(), error.offset()),
+            (Kind::InvalidCombination, 12)
+        );
+        let error = parse("FREQ=DAILY;COUNT=1;UNTIL=20240101");
+        assert_eq!(error.kind(), Kind::InvalidCombination);
+        let error = parse("FREQ=DAILY;INTERVAL=0");
+        assert_eq!(error.kind(), Kind::InvalidValue);
+        let error = parse("FREQ=DAILY;BYSETPOS=1");
+        assert_eq!(error.kind(), Kind::InvalidCombination);
+        let error = parse("FREQ=MONTHLY;BYMONTHDAY=-2147483648");
+        assert_eq!((error.kind(), error.offset()), (Kind::InvalidValue, 24));
+        let error = parse("FREQ=MONTHLY;BYSETPOS=2147483648");
+        assert_eq!(error.kind(), Kind::InvalidValue);
+    }
+}
@@ -0,0 +1,188 @@
+use std::iter::{FusedIterator, Peekable};
+
+use crate::recurrence::local::LocalDateTime;
+use crate::{RecurrenceRule, RecurrenceRuleIter};
+
+/// A set of recurring values, combining the start, the `RRULE`s and `RDATE`s,
+/// and excluding the `EXDATE`s of an RFC 5545 component.
+///
+/// The start is always the first value of the set and counts toward the
+/// `COUNT` of every rule, even if it does not match the rule, as defined by
+/// RFC 5545. Values produced by more than one source are yielded once.
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::{RecurrenceRule, RecurrenceSet};
+/// let d0 = Date::from_calendar_date(2024, January, 1).unwrap();
+/// let d1 = Date::from_calendar_date(2024, January, 15).unwrap();
+/// let rule: RecurrenceRule = "FREQ=WEEKLY;COUNT=3".parse().unwrap();
+/// let set = RecurrenceSet::new(d0.midnight()).with_rule(rule).with_exdate(d1.midnight());
+///
+/// let days: Vec<_> = set.iter().map(|x| x.day()).collect();
+/// assert_eq!(days, vec![1, 8]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecurrenceSet<T> {
+    start: T,
+    rules: Vec<RecurrenceRule>,
+    dates: Vec<T>,
+    exdates: Vec<T>,
+}
+
+impl<T: LocalDateTime> RecurrenceSet<T> {
+    /// Creates a new `RecurrenceSet` with the start as its only value.
+    #[must_use]
+    pub fn new(start: T) -> Self {
+        Self {
+            start,
+            rules: Vec::new(),
+            dates: vec![start],
+            exdates: Vec::new(),
+        }
+    }
+
+    /// Adds the occurrences of the rule, computed from the start.
+    #[must_use]
+    pub fn with_rule(mut self, rule: RecurrenceRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Adds a single value, as an `RDATE` does.
+    #[must_use]
+    pub fn with_date(mut self, date: T) -> Self {
+        let index = self.dates.partition_point(|x| *x <= date);
+        self.dates.insert(index, date);
+        self
+    }
+
+    /// Excludes a single value, as an `EXDATE` does.
+    ///
+    /// Values with an offset are excluded if they refer to the same instant.
+    #[must_use]
+    pub fn with_exdate(mut self, date: T) -> Self {
+        let index = self.exdates.partition_point(|x| *x <= date);
+        self.exdates.insert(index, date);
+        self
+    }
+
+    /// Returns the start of the set.
+    #[must_use]
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// Returns an iterator over the values of the set in ascending order.
+    #[must_use]
+    pub fn iter(&self) -> RecurrenceSetIter<'_, T> {
+        RecurrenceSetIter {
+            rules: self.rules.iter().map(|x| self.occurrences(x)).collect(),
+            dates: &self.dates,
+            exdates: &self.exdates,
+            last: None,
+        }
+    }
+}
+
+impl<T: LocalDateTime> RecurrenceSet<T> {
+    /// Returns the occurrences of the rule, with the start counted as the
+    /// first one when the rule does not produce it itself.
+    fn occurrences(&self, rule: &RecurrenceRule) -> Peekable<RecurrenceRuleIter<T>> {
+        let mut occurrences = rule.occurrences(self.start).peekable();
+        match (rule.count(), occurrences.peek()) {
+            (Some(count), Some(x)) if *x != self.start => {
+                let rule = rule.clone().with_count(count.saturating_sub(1));
+                rule.occurrences(self.start).peekable()
+            }
+            _ => occurrences,
+        }
+    }
+}
+
+impl<'a, T: LocalDateTime> IntoIterator for &'a RecurrenceSet<T> {
+    type Item = T;
+    type IntoIter = RecurrenceSetIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of a [`RecurrenceSet`].
+#[derive(Debug, Clone)]
+pub struct RecurrenceSetIter<'a, T: LocalDateTime> {
+    rules: Vec<Peekable<RecurrenceRuleIter<T>>>,
+    dates: &'a [T],
+    exdates: &'a [T],
+    last: Option<T>,
+}
+
+impl<T: LocalDateTime> Iterator for RecurrenceSetIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mut next = self.dates.first().copied();
+            let mut source = None;
+            for (i, rule) in self.rules.iter_mut().enumerate() {
+                if let Some(&value) = rule.peek() {
+                    if next.is_none_or(|x| value < x) {
+                        next = Some(value);
+                        source = Some(i);
+                    }
+                }
+            }
+
+            let next = next?;
+            match source {
+                Some(i) => _ = self.rules[i].next(),
+                None => self.dates = &self.dates[1..],
+            }
+
+            if self.last.is_some_and(|x| x == next) || self.exdates.binary_search(&next).is_ok() {
+                continue;
+            }
+
+            self.last = Some(next);
+            return Some(next);
+        }
+    }
+}
+
+impl<T: LocalDateTime> FusedIterator for RecurrenceSetIter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use time::Date;
+    use time::Month::*;
+
+    use crate::{RecurrenceRule, RecurrenceSet};
+
+    #[test]
+    fn merge() {
+        let d0 = Date::from_calendar_date(2024, January, 1).unwrap();
+        let d1 = Date::from_calendar_date(2024, January, 10).unwrap();
+        let daily: RecurrenceRule = "FREQ=DAILY;INTERVAL=2;COUNT=3".parse().unwrap();
+        let weekly: RecurrenceRule = "FREQ=WEEKLY;COUNT=2".parse().unwrap();
+        let set = RecurrenceSet::new(d0.with_hms(9, 0, 0).unwrap())
+            .with_rule(daily)
+            .with_rule(weekly)
+            .with_date(d1.with_hms(12, 0, 0).unwrap())
+            .with_exdate(d0.with_hms(9, 0, 0).unwrap());
+
+        let days: Vec<_> = set.iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![3, 5, 8, 10]);
+    }
+
+    #[test]
+    fn unmatched_start() {
+        let d0 = Date::from_calendar_date(2024, January, 1).unwrap();
+        let rule: RecurrenceRule = "FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13;COUNT=3"
+            .parse()
+            .unwrap();
+        let set = RecurrenceSet::new(d0.midnight()).with_rule(rule);
+
+        let dates: Vec<_> = set.iter().map(|x| (x.month(), x.day())).collect();
+        assert_eq!(dates, vec![(January, 1), (September, 13), (December, 13)]);
+    }
+}
@@ -0,0 +1,292 @@
+use std::iter::FusedIterator;
+
+use crate::ext::CalendarExtension;
+use crate::{CalendarDuration, DayPolicy};
+
+/// An iterator over occurrences stepping from an anchor by a [`CalendarDuration`].
+///
+/// The n-th occurrence is computed as `anchor + step * n`, so a clamped day of
+/// the month never carries over into the next occurrence. Occurrences end when
+/// they no longer fit into the range of `T`, or at a bound set by
+/// [`CalendarSeries::with_count`] or [`CalendarSeries::until`].
+///
+/// ```rust
+/// # use time::{Date, Month::*};
+/// # use timext::{CalendarSeries, ext::NumericCalendarDuration};
+/// let d0 = Date::from_calendar_date(2023, January, 31).unwrap();
+/// let series = CalendarSeries::new(d0, 1.months()).with_count(3);
+///
+/// let d1 = Date::from_calendar_date(2023, February, 28).unwrap();
+/// let d2 = Date::from_calendar_date(2023, March, 31).unwrap();
+/// assert_eq!(series.collect::<Vec<_>>(), vec![d0, d1, d2]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CalendarSeries<T> {
+    anchor: T,
+    step: CalendarDuration,
+    policy: DayPolicy,
+    front: u32,
+    back: u32,
+}
+
+impl<T> CalendarSeries<T>
+where
+    T: CalendarExtension + Copy + PartialOrd,
+{
+    /// Creates a new `CalendarSeries` starting at the anchor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step is zero.
+    #[must_use]
+    pub fn new(anchor: T, step: CalendarDuration) -> Self {
+        assert!(!step.is_zero(), "step of `timext::CalendarSeries` is zero");
+
+        let mut series = Self {
+            anchor,
+            step,
+            policy: DayPolicy::Clamp,
+            front: 0,
+            back: u32::MAX,
+        };
+
+        series.back = series.search(|_| false);
+        series
+    }
+
+    /// Sets the [`DayPolicy`] used to compute occurrences.
+    ///
+    /// Returns `None` if the policy is [`DayPolicy::Reject`], as the number of
+    /// occurrences could not be known in advance.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{CalendarSeries, DayPolicy, ext::NumericCalendarDuration};
+    /// let d0 = Date::from_calendar_date(2023, February, 28).unwrap();
+    /// let series = CalendarSeries::new(d0, 1.months()).with_policy(DayPolicy::EndOfMonth);
+    ///
+    /// let d1 = Date::from_calendar_date(2023, March, 31).unwrap();
+    /// assert_eq!(series.unwrap().skip(1).next(), Some(d1));
+    /// ```
+    #[must_use]
+    pub fn with_policy(mut self, policy: DayPolicy) -> Option<Self> {
+        if policy == DayPolicy::Reject {
+            return None;
+        }
+
+        self.policy = policy;
+        self.back = self.search(|_| false);
+        Some(self)
+    }
+
+    /// Limits the series to the provided number of occurrences, counted from the anchor.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{CalendarSeries, ext::NumericCalendarDuration};
+    /// let d0 = Date::from_calendar_date(2023, January, 1).unwrap();
+    /// let series = CalendarSeries::new(d0, 1.months()).with_count(12);
+    /// assert_eq!(series.len(), 12);
+    /// ```
+    #[must_use]
+    pub fn with_count(mut self, count: u32) -> Self {
+        self.back = self.back.min(count).max(self.front);
+        self
+    }
+
+    /// Limits the series to the occurrences that do not step past the provided end.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{CalendarSeries, ext::NumericCalendarDuration};
+    /// let d0 = Date::from_calendar_date(2023, January, 31).unwrap();
+    /// let d1 = Date::from_calendar_date(2023, December, 30).unwrap();
+    /// let series = CalendarSeries::new(d0, 1.months()).until(d1);
+    /// assert_eq!(series.len(), 11);
+    ///
+    /// let d2 = Date::from_calendar_date(2022, December, 31).unwrap();
+    /// let series = CalendarSeries::new(d0, (-1).months()).until(d2);
+    /// assert_eq!(series.last(), Some(d2));
+    /// ```
+    #[must_use]
+    pub fn until(mut self, end: T) -> Self {
+        let negative = self.step.is_negative();
+        self.back = self.search(|x| if negative { x < end } else { x > end });
+        self
+    }
+
+    /// Returns the n-th occurrence counted from the anchor, ignoring any bounds.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month::*};
+    /// # use timext::{CalendarSeries, ext::NumericCalendarDuration};
+    /// let d0 = Date::from_calendar_date(2023, January, 31).unwrap();
+    /// let d1 = Date::from_calendar_date(2024, February, 29).unwrap();
+    /// let series = CalendarSeries::new(d0, 1.months());
+    /// assert_eq!(series.occurrence(13), Some(d1));
+    /// ```
+    #[must_use]
+    pub fn occurrence(&self, n: u32) -> Option<T> {
+        let n = i32::try_from(n).ok()?;
+        let duration = self.step.checked_mul(n)?;
+        self.anchor.checked_calendar_add_with(duration, self.policy)
+    }
+
+    /// Returns the first index in `[front, back]` whose occurrence does not
+    /// exist or satisfies the predicate, which must be monotonic.
+    fn search(&self, past: impl Fn(T) -> bool) -> u32 {
+        let stop = |n| self.occurrence(n).is_none_or(&past);
+
+        let (mut lo, mut hi) = (self.front, self.front);
+        let mut width = 1u32;
+        while hi < self.back && !stop(hi) {
+            lo = hi + 1;
+            hi = hi.saturating_add(width).min(self.back);
+            width = width.saturating_mul(2);
+        }
+
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match stop(mid) {
+                true => hi = mid,
+                false => lo = mid + 1,
+            }
+        }
+
+        hi
+    }
+}
+
+impl<T> Iterator for CalendarSeries<T>
+where
+    T: CalendarExtension + Copy + PartialOrd,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.front += 1;
+        self.occurrence(self.front - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let n = u32::try_from(n).unwrap_or(u32::MAX);
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<T> DoubleEndedIterator for CalendarSeries<T>
+where
+    T: CalendarExtension + Copy + PartialOrd,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.occurrence(self.back)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let n = u32::try_from(n).unwrap_or(u32::MAX);
+        self.back = self.back.saturating_sub(n).max(self.front);
+        self.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for CalendarSeries<T> where T: CalendarExtension + Copy + PartialOrd {}
+
+impl<T> FusedIterator for CalendarSeries<T> where T: CalendarExtension + Copy + PartialOrd {}
+
+#[cfg(test)]
+mod tests {
+    use time::Month::*;
+    use time::{Date, UtcOffset};
+
+    use crate::ext::NumericCalendarDuration;
+    use crate::{CalendarSeries, DayPolicy};
+
+    #[test]
+    fn no_drift() {
+        let d0 = Date::from_calendar_date(2023, January, 31).unwrap();
+        let series = CalendarSeries::new(d0, 1.months());
+        let days: Vec<_> = series.take(6).map(|x| x.day()).collect();
+        assert_eq!(days, vec![31, 28, 31, 30, 31, 30]);
+    }
+
+    #[test]
+    fn bounded_by_range() {
+        let d0 = Date::from_calendar_date(9999, October, 31).unwrap();
+        let series = CalendarSeries::new(d0, 1.months());
+        assert_eq!(series.len(), 3);
+        assert_eq!(series.last(), Some(Date::MAX));
+    }
+
+    #[test]
+    fn until_inclusive() {
+        let d0 = Date::from_calendar_date(2024, January, 31).unwrap();
+        let d1 = Date::from_calendar_date(2024, April, 30).unwrap();
+        let series = CalendarSeries::new(d0, 1.months()).until(d1);
+        assert_eq!(series.clone().len(), 4);
+        assert_eq!(series.last(), Some(d1));
+    }
+
+    #[test]
+    fn double_ended() {
+        let d0 = Date::from_calendar_date(2024, January, 1).unwrap();
+        let mut series = CalendarSeries::new(d0, 1.weeks()).with_count(5);
+        assert_eq!(series.next(), Some(d0));
+        assert_eq!(series.next_back(), Some(d0 + 4.weeks()));
+        assert_eq!(series.len(), 3);
+        assert_eq!(series.nth_back(1), Some(d0 + 2.weeks()));
+        assert_eq!(series.next(), Some(d0 + 1.weeks()));
+        assert_eq!(series.next(), None);
+        assert_eq!(series.next_back(), None);
+    }
+
+    #[test]
+    fn offset_datetime() {
+        let offset = UtcOffset::from_hms(-5, 0, 0).unwrap();
+        let d0 = Date::from_calendar_date(2024, August, 31).unwrap();
+        let d0 = d0.with_hms(9, 30, 0).unwrap().assume_offset(offset);
+        let series = CalendarSeries::new(d0, 3.months()).with_count(3);
+        let dates: Vec<_> = series.map(|x| (x.month(), x.day(), x.hour())).collect();
+        assert_eq!(
+            dates,
+            vec![(August, 31, 9), (November, 30, 9), (February, 28, 9)]
+        );
+    }
+
+    #[test]
+    fn policy() {
+        let d0 = Date::from_calendar_date(2023, February, 28).unwrap();
+        let series = CalendarSeries::new(d0, 1.months());
+        let series = series.with_policy(DayPolicy::EndOfMonth).unwrap();
+        let days: Vec<_> = series.take(3).map(|x| x.day()).collect();
+        assert_eq!(days, vec![28, 31, 30]);
+
+        let series = CalendarSeries::new(d0, 1.months());
+        assert_eq!(series.with_policy(DayPolicy::Reject), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_step() {
+        let d0 = Date::from_calendar_date(2024, January, 1).unwrap();
+        let _ = CalendarSeries::new(d0, 0.months());
+    }
+}
@@ -0,0 +1,13 @@
+use time::util::is_leap_year;
+use time::Month;
+
+/// Returns the number of days in the provided month of the provided year.
+pub(crate) const fn days_in_year_month(year: i32, month: Month) -> u8 {
+    match month {
+        Month::January | Month::March | Month::May | Month::July => 31,
+        Month::August | Month::October | Month::December => 31,
+        Month::April | Month::June | Month::September | Month::November => 30,
+        Month::February if is_leap_year(year) => 29,
+        Month::February => 28,
+    }
+}